@@ -2,19 +2,35 @@
 
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
-use quote::{ToTokens, quote, quote_spanned};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 use syn::{
-    Attribute, Data, DeriveInput, Expr, ExprLit, Fields, GenericParam, Generics, Lit, Type,
-    TypeParamBound, parse_macro_input, parse_quote, spanned::Spanned,
+    Attribute, Data, DeriveInput, Expr, ExprLit, Fields, GenericArgument, GenericParam, Generics,
+    Ident, Lit, LitStr, PathArguments, Type, TypeParamBound, Visibility, parse_macro_input,
+    parse_quote, spanned::Spanned,
 };
 
 /// Derive `HtmplateElement`
-#[proc_macro_derive(HtmplateElement)]
+///
+/// Also generates a typed builder, `T::builder()`, that can render the htmplate without parsing any
+/// HTML. The builder can only be built once every required field has been set.
+///
+/// Fields may be configured using `#[htmplate(...)]`:
+/// * `separator = "..."` splits a `Vec<T>` attribute on the separator instead of on whitespace and
+///   commas.
+/// * `children` makes an `Option<String>` field the HTML of the element's children instead of an
+///   attribute, with `raw` the children are given as they are written instead of having their
+///   htmplates replaced.
+/// * `document` makes an `Option<PathBuf>` field the path of the document containing the element
+///   instead of an attribute.
+/// * `path` resolves a `PathBuf` attribute relative to the directory of the document containing the
+///   element.
+#[proc_macro_derive(HtmplateElement, attributes(htmplate))]
 pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     // Parse the input tokens into a syntax tree.
     let input = parse_macro_input!(input as DeriveInput);
 
     let name = input.ident;
+    let vis = input.vis;
 
     // Add required trait bounds depending on type.
     let generics = add_trait_bounds(input.generics, parse_quote!(FromStr));
@@ -34,7 +50,31 @@ pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenS
         }
     };
 
-    let attributes = fields.iter().map(|field| {
+    let children_field = fields
+        .iter()
+        .find(|field| FieldOptions::from_attributes(&field.attrs).children);
+    if let Some(field) = children_field
+        && !is_option(&field.ty)
+    {
+        panic!("an htmplate children field must be an `Option<String>`")
+    }
+    let document_field = fields
+        .iter()
+        .find(|field| FieldOptions::from_attributes(&field.attrs).document);
+    if let Some(field) = document_field
+        && !is_option(&field.ty)
+    {
+        panic!("an htmplate document field must be an `Option<PathBuf>`")
+    }
+    let attribute_fields: Vec<&syn::Field> = fields
+        .iter()
+        .filter(|field| {
+            let options = FieldOptions::from_attributes(&field.attrs);
+            !options.children && !options.document
+        })
+        .collect();
+
+    let attributes = attribute_fields.iter().map(|field| {
         let name_literal = &field
             .ident
             .as_ref()
@@ -42,7 +82,7 @@ pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenS
             .to_string()
             .to_case(Case::Kebab);
         let description = get_doc(&field.attrs).expect("htmplate fields must have doc comments");
-        let is_required = !is_option(&field.ty);
+        let is_required = !is_option(&field.ty) && !is_vec(&field.ty);
 
         quote_spanned! {field.span()=> htmplate::Attribute {
             name: #name_literal,
@@ -51,7 +91,7 @@ pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenS
         }}
     });
 
-    let get_fields = fields.iter().enumerate().map(|(index, field)| {
+    let get_fields = attribute_fields.iter().enumerate().map(|(index, field)| {
         let name = &field.ident;
         let name_literal = &field
             .ident
@@ -60,8 +100,49 @@ pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenS
             .to_string()
             .to_case(Case::Kebab);
 
+        let options = FieldOptions::from_attributes(&field.attrs);
+
+        if is_vec(&field.ty) {
+            let items = match options.separator {
+                Some(separator) => quote! {
+                    value.split(#separator).map(str::trim).filter(|item| !item.is_empty())
+                },
+                None => quote! {
+                    value
+                        .split(|c: char| c == ',' || c.is_whitespace())
+                        .filter(|item| !item.is_empty())
+                },
+            };
+
+            return quote_spanned! {field.span()=>
+                let #name = match source.attribute(#name_literal) {
+                    Some(value) => {
+                        let mut items = Vec::new();
+                        for (index, item) in #items.enumerate() {
+                            match item.parse() {
+                                Ok(item) => items.push(item),
+                                Err(_) => invalid_items.push(htmplate::InvalidItem {
+                                    attribute: attributes[#index],
+                                    index,
+                                    value: item.to_string(),
+                                }),
+                            }
+                        }
+                        items
+                    }
+                    None => Vec::new(),
+                };
+            };
+        }
+
         let is_required = !is_option(&field.ty);
 
+        let parsed = if options.path {
+            quote! { source.resolve_path(value) }
+        } else {
+            quote! { value }
+        };
+
         let none_action = if is_required {
             quote! {
                 missing_attributes.push(attributes[#index]);
@@ -71,9 +152,9 @@ pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenS
         };
 
         quote_spanned! {field.span()=>
-            let #name = match el.get_attribute(#name_literal) {
+            let #name = match source.attribute(#name_literal) {
                 Some(value) => match value.parse() {
-                    Ok(value) => Some(value),
+                    Ok(value) => Some(#parsed),
                     Err(_) => {
                         invalid_attributes.push(attributes[#index]);
                         None
@@ -87,10 +168,27 @@ pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenS
         }
     });
 
+    let get_children = children_field.map(|field| {
+        let name = &field.ident;
+        quote_spanned! {field.span()=>
+            let #name = source.children().map(str::to_string);
+        }
+    });
+    let accepts_children = children_field.is_some();
+    let raw_children =
+        children_field.is_some_and(|field| FieldOptions::from_attributes(&field.attrs).raw);
+
+    let get_document = document_field.map(|field| {
+        let name = &field.ident;
+        quote_spanned! {field.span()=>
+            let #name = source.document_path().map(|path| path.to_path_buf());
+        }
+    });
+
     let struct_fields = fields.iter().map(|field| {
         let name = &field.ident;
 
-        let is_required = !is_option(&field.ty);
+        let is_required = !is_option(&field.ty) && !is_vec(&field.ty);
 
         let value = if is_required {
             quote! {#name.unwrap()}
@@ -107,6 +205,8 @@ pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenS
 
     let description = get_doc(&input.attrs).expect("An htmplate must have a doc comment");
 
+    let builder = builder(&name, &vis, &generics, &fields);
+
     let implementation = quote! {
         impl #impl_generics htmplate::HtmplateElement for #name #ty_generics #where_clause {
             fn tag() -> &'static str {
@@ -117,26 +217,38 @@ pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenS
                 #description
             }
 
+            fn accepts_children() -> bool {
+                #accepts_children
+            }
+
+            fn raw_children() -> bool {
+                #raw_children
+            }
+
             fn attributes() -> Vec<htmplate::Attribute> {
                 vec![
                     #( #attributes ),*
                 ]
             }
 
-            fn from_element(el: &htmplate::lol_html::html_content::Element, html: &str, path: &std::path::Path) -> Result<Self, htmplate::FromElementError> {
+            fn from_attributes<S: htmplate::AttributeSource + ?Sized>(source: &S) -> Result<Self, htmplate::FromElementError> {
                 let attributes = Self::attributes();
 
                 let mut missing_attributes = vec![];
                 let mut invalid_attributes = vec![];
+                let mut invalid_items = vec![];
 
                 #( #get_fields )*
+                #get_children
+                #get_document
 
-                if !missing_attributes.is_empty() || !invalid_attributes.is_empty() {
+                if !missing_attributes.is_empty() || !invalid_attributes.is_empty() || !invalid_items.is_empty() {
                     let mut error = htmplate::FromElementError {
                         missing_attributes: missing_attributes.into_boxed_slice(),
                         invalid_attributes: invalid_attributes.into_boxed_slice(),
-                        element_tag: el.tag_name(),
-                        element_location: htmplate::Location::from_byte_index(el.source_location().bytes().start, html.as_bytes(), path),
+                        invalid_items: invalid_items.into_boxed_slice(),
+                        element_tag: Self::tag().replace('\\', ""),
+                        element_location: source.location(),
                     };
                     return Err(error);
                 }
@@ -146,11 +258,186 @@ pub fn derive_from_element(input: proc_macro::TokenStream) -> proc_macro::TokenS
                 })
             }
         }
+
+        #builder
     };
 
     proc_macro::TokenStream::from(implementation)
 }
 
+/// Create the typed builder for an htmplate.
+///
+/// Each required field is tracked by a type parameter on the builder that is `htmplate::Unset` until
+/// the field is set, so `build` and `render` only exist once every required field has a value.
+fn builder(
+    name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    fields: &[syn::Field],
+) -> TokenStream {
+    let builder_name = format_ident!("{name}Builder");
+    let builder_doc = format!("A builder for [`{name}`].");
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let struct_params: Vec<&GenericParam> = generics.params.iter().collect();
+    let struct_arguments: Vec<TokenStream> = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            GenericParam::Const(param) => param.ident.to_token_stream(),
+        })
+        .collect();
+
+    let required: Vec<&syn::Field> = fields
+        .iter()
+        .filter(|field| !is_option(&field.ty) && !is_vec(&field.ty))
+        .collect();
+    let states: Vec<Ident> = required
+        .iter()
+        .map(|field| {
+            let field_name = field.ident.as_ref().unwrap().to_string();
+            format_ident!("{}State", field_name.to_case(Case::Pascal))
+        })
+        .collect();
+    let required_types: Vec<&Type> = required.iter().map(|field| &field.ty).collect();
+
+    let builder_fields = fields.iter().map(|field| {
+        let field_name = &field.ident;
+        let ty = &field.ty;
+
+        if let Some(position) = required
+            .iter()
+            .position(|required| required.ident == field.ident)
+        {
+            let state = &states[position];
+            quote! { #field_name: #state }
+        } else {
+            quote! { #field_name: #ty }
+        }
+    });
+
+    let initial_fields = fields.iter().map(|field| {
+        let field_name = &field.ident;
+
+        if is_option(&field.ty) {
+            quote! { #field_name: None }
+        } else if is_vec(&field.ty) {
+            quote! { #field_name: Vec::new() }
+        } else {
+            quote! { #field_name: htmplate::Unset }
+        }
+    });
+    let unset = required.iter().map(|_| quote! { htmplate::Unset });
+
+    let setters = fields.iter().map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let doc = get_doc(&field.attrs).expect("htmplate fields must have doc comments");
+
+        if is_option(&field.ty) {
+            let inner = inner_type(ty);
+            return quote! {
+                #[doc = #doc]
+                pub fn #field_name(mut self, #field_name: impl Into<#inner>) -> Self {
+                    self.#field_name = Some(#field_name.into());
+                    self
+                }
+            };
+        }
+
+        if is_vec(&field.ty) {
+            let inner = inner_type(ty);
+            return quote! {
+                #[doc = #doc]
+                pub fn #field_name(mut self, #field_name: impl IntoIterator<Item = #inner>) -> Self {
+                    self.#field_name = #field_name.into_iter().collect();
+                    self
+                }
+            };
+        }
+
+        let position = required
+            .iter()
+            .position(|required| required.ident == field.ident)
+            .unwrap();
+        let output_states = states.iter().enumerate().map(|(index, state)| {
+            if index == position {
+                ty.to_token_stream()
+            } else {
+                state.to_token_stream()
+            }
+        });
+        let moved_fields = fields.iter().map(|other| {
+            let other_name = &other.ident;
+            if other.ident == field.ident {
+                quote! { #other_name: #field_name.into() }
+            } else {
+                quote! { #other_name: self.#other_name }
+            }
+        });
+
+        quote! {
+            #[doc = #doc]
+            pub fn #field_name(self, #field_name: impl Into<#ty>) -> #builder_name<#( #struct_arguments, )* #( #output_states ),*> {
+                #builder_name {
+                    #( #moved_fields, )*
+                    _htmplate: core::marker::PhantomData,
+                }
+            }
+        }
+    });
+
+    let built_fields = fields.iter().map(|field| {
+        let field_name = &field.ident;
+        quote! { #field_name: self.#field_name }
+    });
+
+    let render_bound = if generics.params.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! { where #name #ty_generics: htmplate::htmplates::ToHtml }
+    };
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Create a builder for this htmplate.
+            #vis fn builder() -> #builder_name<#( #struct_arguments, )* #( #unset ),*> {
+                #builder_name {
+                    #( #initial_fields, )*
+                    _htmplate: core::marker::PhantomData,
+                }
+            }
+        }
+
+        #[doc = #builder_doc]
+        #[must_use]
+        #vis struct #builder_name<#( #struct_params, )* #( #states ),*> #where_clause {
+            #( #builder_fields, )*
+            _htmplate: core::marker::PhantomData<fn() -> #name #ty_generics>,
+        }
+
+        impl<#( #struct_params, )* #( #states ),*> #builder_name<#( #struct_arguments, )* #( #states ),*> #where_clause {
+            #( #setters )*
+        }
+
+        impl #impl_generics #builder_name<#( #struct_arguments, )* #( #required_types ),*> #where_clause {
+            /// Build the htmplate.
+            pub fn build(self) -> #name #ty_generics {
+                #name {
+                    #( #built_fields ),*
+                }
+            }
+
+            /// Build the htmplate and render it to HTML.
+            pub fn render(self) -> Result<String, htmplate::htmplates::HtmplateErrorKind> #render_bound {
+                htmplate::htmplates::render(self.build())
+            }
+        }
+    }
+}
+
 // Add a bound to every type parameter T.
 fn add_trait_bounds(mut generics: Generics, bounds: TypeParamBound) -> Generics {
     for param in &mut generics.params {
@@ -178,11 +465,10 @@ fn get_doc(attrs: &[Attribute]) -> Option<Expr> {
             lit: Lit::Str(lit_str),
             ..
         }) = &line.value
+            && let Some(trimmed) = lit_str.value().strip_prefix(' ')
         {
-            if let Some(trimmed) = lit_str.value().strip_prefix(' ') {
-                trimmed.to_tokens(&mut macro_args);
-                continue;
-            }
+            trimmed.to_tokens(&mut macro_args);
+            continue;
         }
 
         line.value.to_tokens(&mut macro_args);
@@ -195,6 +481,80 @@ fn get_doc(attrs: &[Attribute]) -> Option<Expr> {
     }
 }
 
+/// The options set on a field using `#[htmplate(...)]`.
+#[derive(Default)]
+struct FieldOptions {
+    separator: Option<LitStr>,
+    children: bool,
+    raw: bool,
+    document: bool,
+    path: bool,
+}
+impl FieldOptions {
+    fn from_attributes(attrs: &[Attribute]) -> Self {
+        let mut options = Self::default();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident("htmplate")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("separator") {
+                    options.separator = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("children") {
+                    options.children = true;
+                    Ok(())
+                } else if meta.path.is_ident("raw") {
+                    options.raw = true;
+                    Ok(())
+                } else if meta.path.is_ident("document") {
+                    options.document = true;
+                    Ok(())
+                } else if meta.path.is_ident("path") {
+                    options.path = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported htmplate field option"))
+                }
+            })
+            .unwrap_or_else(|error| panic!("invalid `#[htmplate(...)]` attribute: {error}"));
+        }
+
+        options
+    }
+}
+
+/// Get the type inside of an `Option<T>` or `Vec<T>`.
+fn inner_type(ty: &Type) -> &Type {
+    if let Type::Path(typepath) = ty
+        && let Some(segment) = typepath.path.segments.last()
+        && let PathArguments::AngleBracketed(arguments) = &segment.arguments
+        && let Some(GenericArgument::Type(inner)) = arguments.args.first()
+    {
+        inner
+    } else {
+        panic!("expected a type with a generic argument")
+    }
+}
+
+fn is_vec(ty: &Type) -> bool {
+    match ty {
+        Type::Path(typepath) if typepath.qself.is_none() => {
+            let idents_of_path = typepath
+                .path
+                .segments
+                .iter()
+                .fold(String::new(), |mut acc, v| {
+                    acc.push_str(&v.ident.to_string());
+                    acc.push(':');
+                    acc
+                });
+            ["Vec:", "std:vec:Vec:", "alloc:vec:Vec:"]
+                .into_iter()
+                .any(|s| idents_of_path == s)
+        }
+        _ => false,
+    }
+}
+
 fn is_option(ty: &Type) -> bool {
     match ty {
         Type::Path(typepath) if typepath.qself.is_none() => {
@@ -2,9 +2,15 @@
 
 use core::str::FromStr;
 
+use std::path::Path;
+
+use htmplate::{
+    HtmplateElement, LocatedAttributes, Location,
+    htmplates::{HtmplateErrorKind, ToHtml},
+};
 use htmplate_derive::HtmplateElement;
 
-#[derive(HtmplateElement)]
+#[derive(Debug, HtmplateElement)]
 /// a test struct
 pub struct TestStruct {
     /// a required string
@@ -19,8 +25,24 @@ pub struct TestStruct {
     pub optional_i8: Option<i8>,
     /// an optional custom enum
     pub optional_custom: Option<Custom>,
+    /// a list of strings
+    pub list_str: Vec<String>,
+    /// a list of custom enums
+    #[htmplate(separator = ";")]
+    pub list_custom: Vec<Custom>,
+}
+impl ToHtml for TestStruct {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        Ok(format!(
+            "<p>\n  {} {} {}\n</p>",
+            self.required_str,
+            self.required_i8,
+            self.list_str.join(" ")
+        ))
+    }
 }
 
+#[derive(Debug)]
 pub enum Custom {
     A,
     B,
@@ -38,3 +60,167 @@ impl FromStr for Custom {
         }
     }
 }
+
+#[test]
+fn builder_renders() {
+    let html = TestStruct::builder()
+        .required_custom(Custom::A)
+        .required_str("text")
+        .optional_i8(4)
+        .list_str(["a".to_string(), "b".to_string()])
+        .required_i8(8)
+        .render()
+        .unwrap();
+
+    assert_eq!(html, "<p> text 8 a b </p>");
+}
+
+#[test]
+fn builder_requires_fields() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
+
+#[test]
+fn from_attributes() {
+    let htmplate = TestStruct::from_attributes(&[
+        ("required-str", "text"),
+        ("required-i-8", "8"),
+        ("required-custom", "b"),
+        ("list-str", "a, b c"),
+        ("list-custom", "a; c"),
+    ])
+    .unwrap();
+
+    assert_eq!(htmplate.list_str, ["a", "b", "c"]);
+    assert_eq!(htmplate.list_custom.len(), 2);
+    assert!(htmplate.optional_str.is_none());
+}
+
+#[test]
+fn from_attributes_error() {
+    let error = TestStruct::from_attributes(&LocatedAttributes {
+        attributes: vec![
+            ("required-str", "text"),
+            ("required-i-8", "two"),
+            ("list-custom", "a;d;c"),
+        ],
+        location: Location::new(Path::new("index.html"), 4, 2),
+    })
+    .unwrap_err();
+
+    assert_eq!(error.element_tag, "htmplate:test-struct");
+    assert_eq!(error.missing_attributes[0].name, "required-custom");
+    assert_eq!(error.invalid_attributes[0].name, "required-i-8");
+    assert_eq!(error.invalid_items[0].index, 1);
+    assert_eq!(error.invalid_items[0].value, "d");
+    assert!(
+        error
+            .element_location
+            .unwrap()
+            .to_string()
+            .ends_with("index.html:4:2")
+    );
+}
+
+#[test]
+fn list_separators_skip_empty_items() {
+    let htmplate = TestStruct::from_attributes(&[
+        ("required-str", "text"),
+        ("required-i-8", "8"),
+        ("required-custom", "a"),
+        ("list-str", " a,,b\n c, "),
+        ("list-custom", "a; ;b;;c;"),
+    ])
+    .unwrap();
+
+    assert_eq!(htmplate.list_str, ["a", "b", "c"]);
+    assert_eq!(htmplate.list_custom.len(), 3);
+}
+
+#[test]
+fn list_custom_separator_keeps_commas_and_spaces() {
+    #[derive(Debug, HtmplateElement)]
+    /// a test struct with a custom separator
+    pub struct Separated {
+        /// a list of strings
+        #[htmplate(separator = "|")]
+        pub items: Vec<String>,
+    }
+    impl ToHtml for Separated {
+        fn to_html(self) -> Result<String, HtmplateErrorKind> {
+            Ok(self.items.join("|"))
+        }
+    }
+
+    let htmplate = Separated::from_attributes(&[("items", "a, b | c d |")]).unwrap();
+
+    assert_eq!(htmplate.items, ["a, b", "c d"]);
+}
+
+#[test]
+fn list_invalid_items_are_reported() {
+    let error = TestStruct::from_attributes(&[
+        ("required-str", "text"),
+        ("required-i-8", "8"),
+        ("required-custom", "a"),
+        ("list-custom", "x; a; y"),
+    ])
+    .unwrap_err();
+
+    assert!(error.missing_attributes.is_empty());
+    assert!(error.invalid_attributes.is_empty());
+    assert_eq!(error.invalid_items.len(), 2);
+    assert_eq!(error.invalid_items[0].index, 0);
+    assert_eq!(error.invalid_items[0].value, "x");
+    assert_eq!(error.invalid_items[1].index, 2);
+    assert_eq!(error.invalid_items[1].value, "y");
+    assert_eq!(error.invalid_items[1].attribute.name, "list-custom");
+}
+
+#[test]
+fn missing_list_is_empty() {
+    let htmplate = TestStruct::from_attributes(&[
+        ("required-str", "text"),
+        ("required-i-8", "8"),
+        ("required-custom", "a"),
+    ])
+    .unwrap();
+
+    assert!(htmplate.list_str.is_empty());
+    assert!(htmplate.list_custom.is_empty());
+}
+
+#[test]
+fn from_json_object() {
+    let json = serde_json::json!({
+        "required-str": "text",
+        "required-i-8": 8,
+        "required-custom": "c",
+        "optional-str": null,
+        "list-str": ["a", "b"],
+        "list-custom": "a; b",
+    });
+
+    let htmplate = TestStruct::from_attributes(json.as_object().unwrap()).unwrap();
+
+    assert_eq!(htmplate.required_i8, 8);
+    assert!(htmplate.optional_str.is_none());
+    assert_eq!(htmplate.list_str, ["a", "b"]);
+    assert_eq!(htmplate.list_custom.len(), 2);
+}
+
+#[test]
+fn from_json_object_error_has_no_location() {
+    let json = serde_json::json!({ "required-str": "text", "required-i-8": true });
+
+    let error = TestStruct::from_attributes(json.as_object().unwrap()).unwrap_err();
+
+    assert_eq!(error.invalid_attributes[0].name, "required-i-8");
+    assert_eq!(error.missing_attributes[0].name, "required-custom");
+    assert!(error.element_location.is_none());
+    assert!(
+        error
+            .to_string()
+            .starts_with("invalid `htmplate:test-struct`\n")
+    );
+}
@@ -0,0 +1,6 @@
+use htmplate::htmplates::Alert;
+
+fn main() {
+    // `status` is required, so the builder can't render until it is set.
+    let _ = Alert::builder().text("Saved").render();
+}
@@ -5,10 +5,17 @@ use std::{fs, io, path::Path};
 const CSS: &str = include_str!(concat!(env!("OUT_DIR"), "/style.min.css"));
 
 const BASE64_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/base64.ts"));
+const CODE_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/code.ts"));
+const DIALOG_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/dialog.ts"));
 const FETCH_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/fetch.ts"));
 const FORM_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/form.ts"));
+const HEADER_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/header.ts"));
 const REDIRECT_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/redirect.ts"));
+const TABLE_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/table.ts"));
+const TABS_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/tabs.ts"));
 const TEMPORAL_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/temporal.ts"));
+const TIME_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/time.ts"));
+const TOAST_TS: &str = include_str!(concat!(env!("OUT_DIR"), "/toast.ts"));
 
 const FAVICON: &[u8] = include_bytes!("assets/favicon.ico");
 
@@ -35,10 +42,17 @@ pub fn write_assets(directory: &Path) -> io::Result<()> {
 
     // Write scripts
     fs::write(directory.join("base64.ts"), BASE64_TS)?;
+    fs::write(directory.join("code.ts"), CODE_TS)?;
+    fs::write(directory.join("dialog.ts"), DIALOG_TS)?;
     fs::write(directory.join("fetch.ts"), FETCH_TS)?;
     fs::write(directory.join("form.ts"), FORM_TS)?;
+    fs::write(directory.join("header.ts"), HEADER_TS)?;
     fs::write(directory.join("redirect.ts"), REDIRECT_TS)?;
+    fs::write(directory.join("table.ts"), TABLE_TS)?;
+    fs::write(directory.join("tabs.ts"), TABS_TS)?;
     fs::write(directory.join("temporal.ts"), TEMPORAL_TS)?;
+    fs::write(directory.join("time.ts"), TIME_TS)?;
+    fs::write(directory.join("toast.ts"), TOAST_TS)?;
 
     // Write static
     fs::write(directory.join("favicon.ico"), FAVICON)?;
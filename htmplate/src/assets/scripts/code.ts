@@ -0,0 +1,37 @@
+/**
+ * Copy the code of a code block to the clipboard.
+ */
+export async function copyCode(block: HTMLElement): Promise<boolean> {
+  const code = block.querySelector("code");
+  if (!code) {
+    return false;
+  }
+
+  try {
+    await navigator.clipboard.writeText(code.textContent ?? "");
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+// Copy the code when a code block's copy button is clicked.
+document.addEventListener("click", async (event) => {
+  if (!(event.target instanceof Element)) {
+    return;
+  }
+
+  const button = event.target.closest<HTMLButtonElement>(".code-block > button.copy");
+  const block = button?.parentElement;
+  if (!button || !block) {
+    return;
+  }
+
+  const copied = await copyCode(block);
+  button.ariaLabel = copied ? "Copied" : "Could not copy code";
+  button.dataset.copied = copied ? "true" : "false";
+  setTimeout(() => {
+    button.ariaLabel = "Copy code";
+    delete button.dataset.copied;
+  }, 2000);
+});
@@ -0,0 +1,80 @@
+const openers = new WeakMap<HTMLDialogElement, HTMLElement>();
+
+/**
+ * Open a dialog as a modal, focus is returned to the opener when the dialog closes.
+ *
+ * # Panics
+ * If the dialog does not exist.
+ */
+export function openDialog(id: string, opener: HTMLElement | null = null) {
+  const dialog = getDialogById(id);
+  if (dialog.open) {
+    return;
+  }
+
+  const focused = opener ?? document.activeElement;
+  if (focused instanceof HTMLElement) {
+    openers.set(dialog, focused);
+  }
+
+  dialog.showModal();
+}
+
+/**
+ * Close a dialog.
+ *
+ * # Panics
+ * If the dialog does not exist.
+ */
+export function closeDialog(id: string, returnValue?: string) {
+  getDialogById(id).close(returnValue);
+}
+
+/**
+ * # Panics
+ * If element does not exist or is not a dialog.
+ */
+function getDialogById(id: string): HTMLDialogElement {
+  const dialog = document.getElementById(id);
+  if (!(dialog instanceof HTMLDialogElement)) {
+    throw `dialog '${id}' does not exist`;
+  }
+
+  if (!dialog.dataset.initialised) {
+    dialog.dataset.initialised = "true";
+
+    dialog.addEventListener("close", () => {
+      openers.get(dialog)?.focus();
+      openers.delete(dialog);
+    });
+
+    // Close when the backdrop is clicked, clicks on the dialog's own padding also target the
+    // dialog so the click must be outside of its box.
+    dialog.addEventListener("click", (event) => {
+      if (event.target !== dialog) {
+        return;
+      }
+
+      const rect = dialog.getBoundingClientRect();
+      const inside = rect.left <= event.clientX && event.clientX <= rect.right &&
+        rect.top <= event.clientY && event.clientY <= rect.bottom;
+      if (!inside) {
+        dialog.close();
+      }
+    });
+  }
+
+  return dialog;
+}
+
+// Open dialogs from buttons with a `data-dialog` attribute.
+document.addEventListener("click", (event) => {
+  if (!(event.target instanceof Element)) {
+    return;
+  }
+
+  const trigger = event.target.closest<HTMLElement>("[data-dialog]");
+  if (trigger && trigger.dataset.dialog) {
+    openDialog(trigger.dataset.dialog, trigger);
+  }
+});
@@ -37,25 +37,97 @@ export class FormError {
 }
 
 export class Input {
-  input: HTMLInputElement;
+  input: HTMLInputElement | HTMLSelectElement | HTMLTextAreaElement | HTMLFieldSetElement;
   error: HTMLElement;
+  counter: HTMLElement | null;
+  visibility: HTMLButtonElement | null = null;
+  showingValidationMessage = false;
 
   constructor(formId: string, inputId: string) {
-    this.input = getElementById<HTMLInputElement>(`${formId}${inputId}/input`, HTMLInputElement);
+    this.input = getInputById(`${formId}${inputId}/input`);
     this.error = getElementById<HTMLElement>(`${formId}${inputId}/error`, HTMLElement);
+    this.counter = document.getElementById(`${formId}${inputId}/counter`);
 
     this.input.addEventListener("input", () => {
-      this.input.setCustomValidity("");
+      this.validityElement().setCustomValidity("");
+      this.updateCounter();
+      if (this.showingValidationMessage && this.validityElement().validity.valid) {
+        this.clearError();
+      }
     });
+    this.updateCounter();
+
+    // Surface the browser's validation message through the error element, invalid events don't
+    // bubble so they are captured for radio groups.
+    this.input.addEventListener("invalid", () => {
+      this.showValidationMessage();
+    }, true);
+
+    const visibility = document.getElementById(`${formId}${inputId}/visibility`);
+    if (visibility instanceof HTMLButtonElement && this.input instanceof HTMLInputElement) {
+      const input = this.input;
+      this.visibility = visibility;
+      visibility.addEventListener("click", () => {
+        const show = input.type === "password";
+        input.type = show ? "text" : "password";
+        visibility.ariaPressed = show ? "true" : "false";
+        visibility.ariaLabel = show ? "Hide password" : "Show password";
+      });
+    }
+  }
+
+  showValidationMessage() {
+    // Don't replace errors from the server.
+    if (this.error.textContent !== "!" && !this.showingValidationMessage) {
+      return;
+    }
+    this.showingValidationMessage = true;
+    this.error.classList.remove("hidden");
+    this.error.ariaHidden = "false";
+    this.error.textContent = this.validityElement().validationMessage;
+  }
+
+  /**
+   * The element that holds the input's validity, for radio groups this is the first radio.
+   */
+  validityElement(): HTMLInputElement | HTMLSelectElement | HTMLTextAreaElement {
+    if (this.input instanceof HTMLFieldSetElement) {
+      const radio = this.input.querySelector<HTMLInputElement>('input[type="radio"]');
+      if (!radio) {
+        throw `radio group '${this.input.id}' has no options`;
+      }
+      return radio;
+    }
+    return this.input;
+  }
+
+  updateCounter() {
+    if (
+      !this.counter || this.input instanceof HTMLSelectElement ||
+      this.input instanceof HTMLFieldSetElement
+    ) {
+      return;
+    }
+
+    const length = this.input.value.length;
+    const maxLength = this.input.maxLength;
+    if (maxLength >= 0) {
+      this.counter.textContent = `${length}/${maxLength}`;
+    } else {
+      this.counter.textContent = `${length}`;
+    }
   }
 
   getValue(): string {
-    if (this.input.type === "checkbox") {
+    if (this.input instanceof HTMLInputElement && this.input.type === "checkbox") {
       if (this.input.checked) {
         return "checked";
       } else {
         return "unchecked";
       }
+    } else if (this.input instanceof HTMLFieldSetElement) {
+      const checked = this.input.querySelector<HTMLInputElement>('input[type="radio"]:checked');
+      return checked?.value ?? "";
     } else {
       return this.input.value;
     }
@@ -63,10 +135,14 @@ export class Input {
 
   setLock(lock: boolean) {
     this.input.disabled = lock;
+    if (this.visibility) {
+      this.visibility.disabled = lock;
+    }
   }
 
   clearError() {
-    this.input.setCustomValidity("");
+    this.showingValidationMessage = false;
+    this.validityElement().setCustomValidity("");
     this.error.classList.add("hidden");
     this.error.ariaHidden = "true";
     this.error.textContent = "!";
@@ -74,14 +150,14 @@ export class Input {
 
   addError(error: string) {
     if (this.error.textContent === "!") {
-      this.input.setCustomValidity(error);
+      this.validityElement().setCustomValidity(error);
       this.error.classList.remove("hidden");
       this.error.ariaHidden = "false";
       this.error.textContent = `Invalid value: ${error}`;
       return;
     }
     this.error.textContent += `, ${error}`;
-    this.input.setCustomValidity(this.error.textContent ?? "Invalid value");
+    this.validityElement().setCustomValidity(this.error.textContent ?? "Invalid value");
   }
 }
 
@@ -112,6 +188,7 @@ export class Form {
 
   setLock(lock: boolean) {
     this.submitButton.disabled = lock;
+    this.submitButton.ariaBusy = lock ? "true" : "false";
     for (const input of this.inputs.values()) {
       input.setLock(lock);
     }
@@ -157,3 +234,20 @@ function getElementById<T extends HTMLElement>(id: string, expected: Class<T>):
   }
   return element;
 }
+
+/**
+ * # Panics
+ * If element does not exist or is not an input or radio group.
+ */
+function getInputById(
+  id: string,
+): HTMLInputElement | HTMLSelectElement | HTMLTextAreaElement | HTMLFieldSetElement {
+  const element = document.getElementById(id);
+  if (
+    !(element instanceof HTMLInputElement || element instanceof HTMLSelectElement ||
+      element instanceof HTMLTextAreaElement || element instanceof HTMLFieldSetElement)
+  ) {
+    throw `element '${id}' does not exist`;
+  }
+  return element;
+}
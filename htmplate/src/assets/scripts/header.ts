@@ -0,0 +1,38 @@
+function initialiseHeader(header: HTMLElement) {
+  const toggle = header.querySelector<HTMLButtonElement>(":scope > .menu-toggle");
+  if (toggle) {
+    toggle.addEventListener("click", () => {
+      const expanded = toggle.ariaExpanded === "true";
+      toggle.ariaExpanded = expanded ? "false" : "true";
+    });
+
+    // Close the menu with escape, returning focus to the toggle.
+    header.addEventListener("keydown", (event) => {
+      if (event.key === "Escape" && toggle.ariaExpanded === "true") {
+        toggle.ariaExpanded = "false";
+        toggle.focus();
+      }
+    });
+  }
+
+  // Highlight the link to the current page if the template didn't.
+  const links = Array.from(header.querySelectorAll<HTMLAnchorElement>("nav a[href]"));
+  if (!links.some((link) => link.ariaCurrent === "page")) {
+    const path = trimSlash(location.pathname);
+    const current = links.find((link) => trimSlash(new URL(link.href).pathname) === path);
+    if (current) {
+      current.ariaCurrent = "page";
+    }
+  }
+
+  // Without this script the links are never collapsed into a menu.
+  header.dataset.enhanced = "true";
+}
+
+function trimSlash(path: string): string {
+  return path.replace(/\/(index\.html)?$/, "") || "/";
+}
+
+for (const header of document.querySelectorAll<HTMLElement>(".site-header")) {
+  initialiseHeader(header);
+}
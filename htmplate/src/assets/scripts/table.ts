@@ -0,0 +1,92 @@
+import { Temporal } from "./temporal.ts";
+
+type ColumnType = "text" | "number" | "date";
+
+/**
+ * Sort a table's rows by a column, the column's header is updated with the sort direction.
+ */
+export function sortTable(table: HTMLTableElement, column: number, ascending: boolean) {
+  const header = table.tHead?.rows[0]?.cells[column];
+  const body = table.tBodies[0];
+  if (!header || !body) {
+    return;
+  }
+
+  const type = (header.dataset.type ?? "text") as ColumnType;
+  const rows = Array.from(body.rows).map((row) => ({
+    row,
+    key: sortKey(row.cells[column]?.textContent?.trim() ?? "", type),
+  }));
+
+  rows.sort((a, b) => {
+    const order = compare(a.key, b.key);
+    return ascending ? order : -order;
+  });
+  body.append(...rows.map(({ row }) => row));
+
+  for (const other of table.tHead?.rows[0]?.cells ?? []) {
+    other.ariaSort = other === header ? (ascending ? "ascending" : "descending") : "none";
+  }
+}
+
+function sortKey(value: string, type: ColumnType): string | number {
+  switch (type) {
+    case "number": {
+      const number = Number.parseFloat(value.replace(/[^0-9.eE+-]/g, ""));
+      return Number.isNaN(number) ? Number.POSITIVE_INFINITY : number;
+    }
+    case "date":
+      return parseDate(value);
+    default:
+      return value.toLowerCase();
+  }
+}
+
+/**
+ * The epoch milliseconds of an ISO 8601 instant, date time or date, dates without an offset are
+ * treated as UTC.
+ */
+function parseDate(value: string): number {
+  try {
+    return Temporal.Instant.from(value).epochMilliseconds;
+  } catch {
+    // Not an instant.
+  }
+  try {
+    return Temporal.PlainDateTime.from(value).toZonedDateTime("UTC").epochMilliseconds;
+  } catch {
+    return Number.POSITIVE_INFINITY;
+  }
+}
+
+function compare(a: string | number, b: string | number): number {
+  if (typeof a === "number" && typeof b === "number") {
+    if (a === b) {
+      return 0;
+    }
+    return a < b ? -1 : 1;
+  }
+  return String(a).localeCompare(String(b));
+}
+
+function initialiseTable(table: HTMLTableElement) {
+  const headers = table.tHead?.rows[0]?.cells ?? [];
+
+  for (const [index, header] of Array.from(headers).entries()) {
+    // Headers become buttons so they can be sorted from the keyboard.
+    const button = document.createElement("button");
+    button.type = "button";
+    button.className = "ghost";
+    button.append(...header.childNodes);
+    header.append(button);
+    header.ariaSort = "none";
+
+    button.addEventListener("click", () => {
+      sortTable(table, index, header.ariaSort !== "ascending");
+    });
+  }
+}
+
+for (const table of document.querySelectorAll<HTMLTableElement>("table.table")) {
+  initialiseTable(table);
+}
@@ -0,0 +1,73 @@
+/**
+ * Select a tab, showing its panel and hiding the other panels in its tabs.
+ */
+export function selectTab(tab: HTMLElement, focus = false) {
+  const tablist = tab.closest('[role="tablist"]');
+  if (!tablist) {
+    return;
+  }
+
+  for (const other of tablist.querySelectorAll<HTMLElement>('[role="tab"]')) {
+    const selected = other === tab;
+    other.ariaSelected = selected ? "true" : "false";
+    other.tabIndex = selected ? 0 : -1;
+
+    const panelId = other.getAttribute("aria-controls");
+    const panel = panelId ? document.getElementById(panelId) : null;
+    if (panel) {
+      panel.hidden = !selected;
+    }
+  }
+
+  if (focus) {
+    tab.focus();
+  }
+}
+
+function initialiseTabs(tabs: HTMLElement) {
+  const tablist = tabs.querySelector<HTMLElement>(':scope > [role="tablist"]');
+  if (!tablist) {
+    return;
+  }
+  const tabButtons = Array.from(tablist.querySelectorAll<HTMLElement>('[role="tab"]'));
+
+  for (const [index, tab] of tabButtons.entries()) {
+    tab.addEventListener("click", () => selectTab(tab));
+
+    // Arrow keys move between tabs, wrapping at either end.
+    tab.addEventListener("keydown", (event) => {
+      let next: number;
+      switch (event.key) {
+        case "ArrowLeft":
+          next = (index - 1 + tabButtons.length) % tabButtons.length;
+          break;
+        case "ArrowRight":
+          next = (index + 1) % tabButtons.length;
+          break;
+        case "Home":
+          next = 0;
+          break;
+        case "End":
+          next = tabButtons.length - 1;
+          break;
+        default:
+          return;
+      }
+
+      event.preventDefault();
+      selectTab(tabButtons[next], true);
+    });
+  }
+
+  const selected = tabButtons.find((tab) => tab.ariaSelected === "true") ?? tabButtons[0];
+  if (selected) {
+    selectTab(selected);
+  }
+
+  // Without this script all panels are shown and the tablist is hidden.
+  tabs.dataset.enhanced = "true";
+}
+
+for (const tabs of document.querySelectorAll<HTMLElement>(".tabs")) {
+  initialiseTabs(tabs);
+}
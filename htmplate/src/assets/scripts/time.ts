@@ -0,0 +1,72 @@
+import { parseUtcToLocalDateTime, Temporal } from "./temporal.ts";
+
+const UNITS: [Intl.RelativeTimeFormatUnit, number][] = [
+  ["year", 365 * 24 * 60 * 60],
+  ["month", 30 * 24 * 60 * 60],
+  ["week", 7 * 24 * 60 * 60],
+  ["day", 24 * 60 * 60],
+  ["hour", 60 * 60],
+  ["minute", 60],
+  ["second", 1],
+];
+
+/**
+ * Describe a timestamp relative to now, e.g. "3 hours ago".
+ */
+export function formatRelative(datetime: Temporal.ZonedDateTime): string {
+  const seconds = datetime.toInstant().since(Temporal.Now.instant()).total("seconds");
+  const formatter = new globalThis.Intl.RelativeTimeFormat(undefined, { numeric: "auto" });
+
+  for (const [unit, unitSeconds] of UNITS) {
+    if (Math.abs(seconds) >= unitSeconds || unit === "second") {
+      return formatter.format(Math.round(seconds / unitSeconds), unit);
+    }
+  }
+  return formatter.format(0, "second");
+}
+
+/**
+ * Show a `<time>` element in the viewer's time zone.
+ */
+export function localiseTime(time: HTMLTimeElement) {
+  let datetime: Temporal.ZonedDateTime;
+  try {
+    datetime = parseUtcToLocalDateTime(time.dateTime);
+  } catch {
+    return;
+  }
+
+  const full = datetime.toLocaleString(undefined, { dateStyle: "medium", timeStyle: "short" });
+
+  switch (time.dataset.format) {
+    case "relative":
+      time.textContent = formatRelative(datetime);
+      time.title = full;
+      break;
+    case "date":
+      time.textContent = datetime.toLocaleString(undefined, { dateStyle: "medium" });
+      time.title = full;
+      break;
+    default:
+      time.textContent = full;
+      break;
+  }
+}
+
+function localiseAll() {
+  for (const time of document.querySelectorAll<HTMLTimeElement>("time.timestamp")) {
+    localiseTime(time);
+  }
+}
+
+localiseAll();
+// Keep relative times up to date.
+setInterval(() => {
+  for (
+    const time of document.querySelectorAll<HTMLTimeElement>(
+      'time.timestamp[data-format="relative"]',
+    )
+  ) {
+    localiseTime(time);
+  }
+}, 60 * 1000);
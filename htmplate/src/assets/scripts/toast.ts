@@ -0,0 +1,63 @@
+import { ServerResponse } from "./fetch.ts";
+
+export type ToastStatus = "error" | "warning" | "success" | "info" | "basic";
+
+/**
+ * Show a toast in the toasts container, the toast is removed after `duration` milliseconds.
+ *
+ * # Panics
+ * If the toasts container does not exist.
+ */
+export function showToast(status: ToastStatus, text: string, duration = 5000): HTMLElement {
+  const container = document.querySelector<HTMLElement>(".toasts");
+  if (!container) {
+    throw "toasts container does not exist";
+  }
+
+  const template = container.querySelector<HTMLTemplateElement>(
+    `template[data-status="${status}"]`,
+  );
+  const toast = template?.content.firstElementChild?.cloneNode(true);
+  if (!(toast instanceof HTMLElement)) {
+    throw `toasts container has no '${status}' template`;
+  }
+
+  const content = toast.querySelector("div");
+  if (content) {
+    content.textContent = text;
+  }
+  toast.classList.add("toast");
+
+  // Errors are announced immediately.
+  if (status === "error") {
+    toast.role = "alert";
+  }
+
+  const remove = () => toast.remove();
+  toast.addEventListener("click", remove);
+  setTimeout(remove, duration);
+
+  container.append(toast);
+  return toast;
+}
+
+/**
+ * Show a toast for a response that was not ok, returns if a toast was shown.
+ */
+export function showResponseToast(response: ServerResponse<unknown>, action: string): boolean {
+  switch (response.status) {
+    case "ok":
+      return false;
+    case "badRequest": {
+      const problems = response.problems.map((problem) => problem.detail).join(", ");
+      showToast("error", `Could not ${action}: ${problems}`);
+      return true;
+    }
+    case "unauthenticated":
+      showToast("warning", `You must be logged in to ${action}.`);
+      return true;
+    case "error":
+      showToast("error", `Something went wrong while trying to ${action}. Try again later.`);
+      return true;
+  }
+}
@@ -0,0 +1,182 @@
+use core::{borrow::Borrow, hash::BuildHasher, hash::Hash};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+use crate::Location;
+
+/// A source of attributes that an htmplate can be created from.
+pub trait AttributeSource {
+    /// Get the value of an attribute.
+    fn attribute(&self, name: &str) -> Option<String>;
+
+    /// Get the location of the source, used when reporting errors.
+    fn location(&self) -> Option<Location> {
+        None
+    }
+
+    /// Get the HTML of the source's children.
+    fn children(&self) -> Option<&str> {
+        None
+    }
+
+    /// Get the path of the document the attributes are from.
+    fn document_path(&self) -> Option<&Path> {
+        None
+    }
+
+    /// Get the directory that relative paths in the attributes are relative to.
+    fn directory(&self) -> Option<&Path> {
+        self.document_path().and_then(Path::parent)
+    }
+
+    /// Resolve a path from an attribute relative to the source's directory.
+    fn resolve_path(&self, path: PathBuf) -> PathBuf {
+        match self.directory() {
+            Some(directory) => directory.join(path),
+            None => path,
+        }
+    }
+}
+
+impl<K, V, S> AttributeSource for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: AsRef<str>,
+    S: BuildHasher,
+{
+    fn attribute(&self, name: &str) -> Option<String> {
+        self.get(name).map(|value| value.as_ref().to_string())
+    }
+}
+
+impl<K, V> AttributeSource for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+    V: AsRef<str>,
+{
+    fn attribute(&self, name: &str) -> Option<String> {
+        self.get(name).map(|value| value.as_ref().to_string())
+    }
+}
+
+impl<K, V> AttributeSource for [(K, V)]
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    fn attribute(&self, name: &str) -> Option<String> {
+        self.iter()
+            .find(|(key, _)| key.as_ref() == name)
+            .map(|(_, value)| value.as_ref().to_string())
+    }
+}
+
+impl<K, V, const N: usize> AttributeSource for [(K, V); N]
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    fn attribute(&self, name: &str) -> Option<String> {
+        self.as_slice().attribute(name)
+    }
+}
+
+impl<K, V> AttributeSource for Vec<(K, V)>
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    fn attribute(&self, name: &str) -> Option<String> {
+        self.as_slice().attribute(name)
+    }
+}
+
+/// Attributes from a JSON object, strings are used as they are, numbers and booleans are written
+/// out, arrays are joined with commas so they suit lists split on the default separator, and
+/// `null` is treated as a missing attribute.
+impl AttributeSource for serde_json::Map<String, serde_json::Value> {
+    fn attribute(&self, name: &str) -> Option<String> {
+        self.get(name).and_then(json_attribute)
+    }
+}
+
+fn json_attribute(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(value) => Some(value.clone()),
+        serde_json::Value::Array(items) => Some(
+            items
+                .iter()
+                .filter_map(json_attribute)
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        value => Some(value.to_string()),
+    }
+}
+
+/// Some attributes and the location they came from.
+#[derive(Debug, Clone)]
+pub struct LocatedAttributes<S> {
+    /// The attributes.
+    pub attributes: S,
+    /// The location of the attributes.
+    pub location: Location,
+}
+impl<S: AttributeSource> AttributeSource for LocatedAttributes<S> {
+    fn attribute(&self, name: &str) -> Option<String> {
+        self.attributes.attribute(name)
+    }
+
+    fn location(&self) -> Option<Location> {
+        Some(self.location.clone())
+    }
+}
+
+/// The attributes of an element in a document.
+#[derive(Debug, Clone)]
+pub(crate) struct ElementAttributes {
+    pub attributes: Vec<(String, String)>,
+    pub location: Location,
+    pub document_path: PathBuf,
+    pub children: Option<String>,
+}
+impl ElementAttributes {
+    pub fn new(
+        element: &lol_html::html_content::Element,
+        location: Location,
+        document_path: PathBuf,
+    ) -> Self {
+        let attributes = element
+            .attributes()
+            .iter()
+            .map(|attribute| (attribute.name(), attribute.value()))
+            .collect();
+
+        Self {
+            attributes,
+            location,
+            document_path,
+            children: None,
+        }
+    }
+}
+impl AttributeSource for ElementAttributes {
+    fn attribute(&self, name: &str) -> Option<String> {
+        self.attributes.attribute(name)
+    }
+
+    fn location(&self) -> Option<Location> {
+        Some(self.location.clone())
+    }
+
+    fn children(&self) -> Option<&str> {
+        self.children.as_deref()
+    }
+
+    fn document_path(&self) -> Option<&Path> {
+        Some(&self.document_path)
+    }
+}
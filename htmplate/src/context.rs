@@ -0,0 +1,204 @@
+use core::ops::Range;
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use crate::Location;
+
+/// A document that is having its htmplates replaced.
+#[derive(Debug)]
+pub(crate) struct Document {
+    pub html: String,
+    pub path: PathBuf,
+}
+
+/// The context of a single pass over some HTML in a document.
+///
+/// Htmplates with children are replaced once their end tag is reached, their children are replaced
+/// in a nested pass over the children's HTML, so each pass knows where its HTML starts in the
+/// document.
+#[derive(Debug, Clone)]
+pub(crate) struct Context {
+    document: Rc<Document>,
+    offset: usize,
+    suppressed: Rc<Cell<usize>>,
+    form: Option<String>,
+    includes: Vec<PathBuf>,
+    blocks: Option<Rc<HashMap<String, String>>>,
+}
+impl Context {
+    pub fn new(html: &str, path: &Path) -> Self {
+        Self {
+            document: Rc::new(Document {
+                html: html.to_string(),
+                path: path.to_path_buf(),
+            }),
+            offset: 0,
+            suppressed: Rc::new(Cell::new(0)),
+            form: None,
+            includes: Vec::new(),
+            blocks: None,
+        }
+    }
+
+    /// Get the source HTML for a range of this pass.
+    pub fn source(&self, range: Range<usize>) -> &str {
+        &self.document.html[self.offset + range.start..self.offset + range.end]
+    }
+
+    /// Get the location of a byte index in this pass.
+    pub fn location(&self, index: usize) -> Location {
+        Location::from_byte_index(
+            self.offset + index,
+            self.document.html.as_bytes(),
+            &self.document.path,
+        )
+    }
+
+    /// Get the path of the document.
+    pub fn path(&self) -> &Path {
+        &self.document.path
+    }
+
+    /// Create the context for a nested pass over a range of this pass.
+    pub fn nested(&self, start: usize) -> Self {
+        Self {
+            document: Rc::clone(&self.document),
+            offset: self.offset + start,
+            suppressed: Rc::new(Cell::new(0)),
+            form: self.form.clone(),
+            includes: self.includes.clone(),
+            blocks: self.blocks.clone(),
+        }
+    }
+
+    /// Create the context for a pass over a file included by this pass.
+    ///
+    /// Returns the chain of includes if the file is already being included.
+    pub fn include(&self, html: &str, path: &Path) -> Result<Self, Vec<PathBuf>> {
+        let mut includes = self.includes.clone();
+        includes.push(self.document.path.clone());
+
+        if includes.iter().any(|include| is_same_file(include, path)) {
+            includes.push(path.to_path_buf());
+            return Err(includes);
+        }
+
+        Ok(Self {
+            document: Rc::new(Document {
+                html: html.to_string(),
+                path: path.to_path_buf(),
+            }),
+            offset: 0,
+            suppressed: Rc::new(Cell::new(0)),
+            form: self.form.clone(),
+            includes,
+            blocks: self.blocks.clone(),
+        })
+    }
+
+    /// The content of the blocks filled by the page using the layout this pass is over.
+    pub fn blocks(&self) -> Option<&HashMap<String, String>> {
+        self.blocks.as_deref()
+    }
+
+    /// Set the content of the blocks filled by the page using the layout this pass is over.
+    pub fn with_blocks(mut self, blocks: HashMap<String, String>) -> Self {
+        self.blocks = Some(Rc::new(blocks));
+        self
+    }
+
+    /// The ID of the form this pass is inside of.
+    pub fn form(&self) -> Option<&str> {
+        self.form.as_deref()
+    }
+
+    /// Set the ID of the form this pass is inside of.
+    pub fn with_form(mut self, form: String) -> Self {
+        self.form = Some(form);
+        self
+    }
+
+    /// If elements should be left alone as they are inside of an htmplate that will replace its
+    /// children.
+    pub fn is_suppressed(&self) -> bool {
+        self.suppressed.get() > 0
+    }
+
+    /// Start leaving elements alone.
+    pub fn suppress(&self) {
+        self.suppressed.set(self.suppressed.get() + 1);
+    }
+
+    /// Stop leaving elements alone.
+    pub fn unsuppress(&self) {
+        self.suppressed.set(self.suppressed.get().saturating_sub(1));
+    }
+}
+
+fn is_same_file(a: &Path, b: &Path) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::Context;
+
+    const HTML: &str = "<main>\n  <div>\n    <p>text</p>\n  </div>\n</main>";
+
+    #[test]
+    fn nested_source_and_location() {
+        let context = Context::new(HTML, Path::new("index.html"));
+        let div = HTML.find("<div>").unwrap();
+        let nested = context.nested(div);
+
+        assert_eq!(nested.source(0..5), "<div>");
+        assert!(nested.location(0).to_string().ends_with("index.html:2:3"));
+
+        let p = HTML[div..].find("<p>").unwrap();
+        let twice_nested = nested.nested(p);
+        assert_eq!(twice_nested.source(3..7), "text");
+        assert!(
+            twice_nested
+                .location(3)
+                .to_string()
+                .ends_with("index.html:3:8")
+        );
+    }
+
+    #[test]
+    fn suppression_is_counted_and_shared_by_clones() {
+        let context = Context::new(HTML, Path::new("index.html"));
+        let clone = context.clone();
+
+        context.suppress();
+        context.suppress();
+        assert!(clone.is_suppressed());
+
+        clone.unsuppress();
+        assert!(context.is_suppressed());
+        clone.unsuppress();
+        assert!(!context.is_suppressed());
+
+        // Unsuppressing too often doesn't underflow.
+        context.unsuppress();
+        assert!(!context.is_suppressed());
+    }
+
+    #[test]
+    fn nested_passes_are_not_suppressed() {
+        let context = Context::new(HTML, Path::new("index.html"));
+        context.suppress();
+
+        assert!(!context.nested(0).is_suppressed());
+    }
+}
@@ -2,14 +2,35 @@ use std::path::Path;
 
 use ts_path::{DisplayPath, RelativePath};
 
+use crate::{AttributeSource, attribute_source::ElementAttributes};
+
 /// A trait marking a struct as an htmplate.
 pub trait HtmplateElement: Sized {
+    /// Try convert some attributes to this htmplate.
+    fn from_attributes<S: AttributeSource + ?Sized>(source: &S) -> Result<Self, FromElementError>;
+
     /// Try convert an element to this htmplate.
     fn from_element(
         el: &lol_html::html_content::Element,
         html: &str,
         path: &Path,
-    ) -> Result<Self, FromElementError>;
+    ) -> Result<Self, FromElementError> {
+        let location =
+            Location::from_byte_index(el.source_location().bytes().start, html.as_bytes(), path);
+        Self::from_attributes(&ElementAttributes::new(el, location, path.to_path_buf()))
+    }
+
+    /// If the htmplate is made from its children, if so the htmplate is replaced once its end tag
+    /// is reached.
+    fn accepts_children() -> bool {
+        false
+    }
+
+    /// If the htmplate's children are given as they are written instead of having their htmplates
+    /// replaced.
+    fn raw_children() -> bool {
+        false
+    }
 
     /// Get the template's attributes
     fn attributes() -> Vec<Attribute>;
@@ -21,6 +42,10 @@ pub trait HtmplateElement: Sized {
     fn description() -> &'static str;
 }
 
+/// A required field of an htmplate builder that has not been set.
+#[derive(Debug, Clone, Copy)]
+pub struct Unset;
+
 /// An attribute on an htmplate.
 #[derive(Debug, Clone, Copy)]
 pub struct Attribute {
@@ -32,6 +57,17 @@ pub struct Attribute {
     pub required: bool,
 }
 
+/// An item in a list attribute that could not be parsed.
+#[derive(Debug, Clone)]
+pub struct InvalidItem {
+    /// The list attribute the item is from.
+    pub attribute: Attribute,
+    /// The index of the item in the list.
+    pub index: usize,
+    /// The item's value.
+    pub value: String,
+}
+
 #[derive(Debug, Clone)]
 /// A location in a file.
 pub struct Location {
@@ -43,6 +79,15 @@ pub struct Location {
     column: usize,
 }
 impl Location {
+    /// Create a location from a path and a position in the file.
+    pub fn new(path: &Path, line: usize, column: usize) -> Self {
+        Self {
+            path: path.relative_to_cwd().opinionated_display(),
+            line,
+            column,
+        }
+    }
+
     /// Convert a byte position to a file position.
     pub fn from_byte_index(index: usize, raw_file: &[u8], path: &Path) -> Self {
         let mut consumed = 0;
@@ -82,17 +127,17 @@ impl core::fmt::Display for Location {
 pub struct FromElementError {
     pub missing_attributes: Box<[Attribute]>,
     pub invalid_attributes: Box<[Attribute]>,
+    pub invalid_items: Box<[InvalidItem]>,
     pub element_tag: String,
-    pub element_location: Location,
+    pub element_location: Option<Location>,
 }
 
 impl core::fmt::Display for FromElementError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        writeln!(
-            f,
-            "invalid `{}` at {}",
-            self.element_tag, self.element_location
-        )?;
+        match &self.element_location {
+            Some(location) => writeln!(f, "invalid `{}` at {}", self.element_tag, location)?,
+            None => writeln!(f, "invalid `{}`", self.element_tag)?,
+        }
 
         for attribute in &self.missing_attributes {
             writeln!(
@@ -110,6 +155,14 @@ impl core::fmt::Display for FromElementError {
             )?;
         }
 
+        for item in &self.invalid_items {
+            writeln!(
+                f,
+                "  invalid item `{}` at index {} of attribute `{}`, {}",
+                item.value, item.index, item.attribute.name, item.attribute.description
+            )?;
+        }
+
         Ok(())
     }
 }
@@ -9,7 +9,9 @@ use crate::{
 
 use crate as htmplate;
 
+/// The style of an alert.
 #[derive(Clone, Debug)]
+#[allow(missing_docs)]
 pub enum AlertStyle {
     Error,
     Warning,
@@ -17,6 +19,29 @@ pub enum AlertStyle {
     Info,
     Basic,
 }
+impl AlertStyle {
+    /// The class for the style.
+    pub fn class(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Success => "success",
+            Self::Info => "info",
+            Self::Basic => "",
+        }
+    }
+
+    /// The icon for the style.
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Error => Icon::AlertCircle.svg(),
+            Self::Warning => Icon::Warning.svg(),
+            Self::Success => Icon::CheckmarkCircle.svg(),
+            Self::Info => Icon::HelpCircle.svg(),
+            Self::Basic => Icon::InformationCircle.svg(),
+        }
+    }
+}
 impl FromStr for AlertStyle {
     type Err = ();
 
@@ -41,30 +66,32 @@ pub struct Alert {
     pub text: Option<String>,
 }
 
+impl Alert {
+    /// The markup before and after the text of an alert, for wrapping text that is rendered
+    /// separately.
+    pub(crate) fn around_text(status: &AlertStyle) -> (String, String) {
+        const TEXT: &str = "{text}";
+
+        let html = format!(
+            include_str!("template.html"),
+            style = status.class(),
+            icon = status.icon(),
+            text = TEXT,
+        );
+        let (open, close) = html.split_once(TEXT).unwrap_or((&html, ""));
+
+        (open.to_string(), close.to_string())
+    }
+}
+
 impl ToHtml for Alert {
     fn to_html(self) -> Result<String, HtmplateErrorKind> {
         let Self { status, text } = self;
 
-        let icon = match &status {
-            AlertStyle::Error => Icon::AlertCircle.svg(),
-            AlertStyle::Warning => Icon::Warning.svg(),
-            AlertStyle::Success => Icon::CheckmarkCircle.svg(),
-            AlertStyle::Info => Icon::HelpCircle.svg(),
-            AlertStyle::Basic => Icon::InformationCircle.svg(),
-        };
-
-        let style = match &status {
-            AlertStyle::Error => "error",
-            AlertStyle::Warning => "warning",
-            AlertStyle::Success => "success",
-            AlertStyle::Info => "info",
-            AlertStyle::Basic => "",
-        };
-
         let html = format!(
             include_str!("template.html"),
-            style = style,
-            icon = icon,
+            style = status.class(),
+            icon = status.icon(),
             text = text.unwrap_or_default(),
         );
 
@@ -0,0 +1,281 @@
+use core::cell::Cell;
+use std::path::{Path, PathBuf};
+
+use htmplate_derive::HtmplateElement;
+use lol_html::{Settings, element, html_content::ContentType, rewrite_str};
+
+use crate::{
+    htmplates::{HtmplateErrorKind, ToHtml, link::normalise},
+    icon::Icon,
+};
+
+use crate as htmplate;
+
+#[derive(HtmplateElement)]
+/// breadcrumbs to the current page, from the child links or the page's path
+pub struct Breadcrumbs {
+    /// this should be the path to the root of the website relative to this document, used when there are no child links, defaults to "./"
+    pub root: Option<String>,
+    /// this should be the text of the link to the root of the website, defaults to "Home"
+    pub home: Option<String>,
+    /// this should be an identifier for a filled ionicon https://ionic.io/ionicons between the links, defaults to chevron-forward
+    pub separator: Option<Icon>,
+    /// the `<a>` elements of the breadcrumbs
+    #[htmplate(children)]
+    pub children: Option<String>,
+    /// the path of the document, used to find the breadcrumbs if there are no child links
+    #[htmplate(document)]
+    pub document: Option<PathBuf>,
+}
+
+impl ToHtml for Breadcrumbs {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            root,
+            home,
+            separator,
+            children,
+            document,
+        } = self;
+
+        let separator = format!(
+            r#"<li class="separator" aria-hidden="true">{}</li>"#,
+            separator.unwrap_or(Icon::ChevronForward).svg()
+        );
+
+        let children = children.unwrap_or_default();
+        let links = count_links(&children)?;
+        let items = if links > 0 {
+            items_from_children(&children, links, &separator)?
+        } else {
+            let root = root.unwrap_or_else(|| "./".to_string());
+            if root.starts_with('/') || root.contains("://") {
+                return Err(HtmplateErrorKind::invalid_attribute(
+                    "root",
+                    "this should be relative to this document, like `../`",
+                ));
+            }
+            let home = home.unwrap_or_else(|| "Home".to_string());
+            let crumbs = crumbs_from_path(document.as_deref().unwrap_or(Path::new("")), &root);
+
+            let last = crumbs.len();
+            core::iter::once((root.clone(), home))
+                .chain(crumbs)
+                .enumerate()
+                .map(|(index, (href, text))| {
+                    let current = if index == last {
+                        r#"aria-current="page""#
+                    } else {
+                        ""
+                    };
+                    format!(r#"<li><a href="{href}" {current}>{text}</a></li>"#)
+                })
+                .collect::<Vec<_>>()
+                .join(&separator)
+        };
+
+        Ok(format!(include_str!("template.html"), items = items))
+    }
+}
+
+/// Count the `<a>` elements in the children.
+fn count_links(children: &str) -> Result<usize, HtmplateErrorKind> {
+    let count = Cell::new(0);
+    rewrite_str(
+        children,
+        Settings {
+            element_content_handlers: vec![element!("a", |_| {
+                count.set(count.get() + 1);
+                Ok(())
+            })],
+            ..Settings::new()
+        },
+    )
+    .map_err(HtmplateErrorKind::invalid_children)?;
+
+    Ok(count.get())
+}
+
+/// Wrap the child links in list items, the last of the `count` links is the current page.
+fn items_from_children(
+    children: &str,
+    count: usize,
+    separator: &str,
+) -> Result<String, HtmplateErrorKind> {
+    let index = Cell::new(0);
+    rewrite_str(
+        children,
+        Settings {
+            element_content_handlers: vec![element!("a", |el| {
+                if index.get() > 0 {
+                    el.before(separator, ContentType::Html);
+                }
+                index.set(index.get() + 1);
+                if index.get() == count {
+                    el.set_attribute("aria-current", "page")?;
+                }
+
+                el.before("<li>", ContentType::Html);
+                el.after("</li>", ContentType::Html);
+                Ok(())
+            })],
+            ..Settings::new()
+        },
+    )
+    .map_err(HtmplateErrorKind::invalid_children)
+}
+
+/// Get the links to each directory between the root and the document, and to the document.
+fn crumbs_from_path(document: &Path, root: &str) -> Vec<(String, String)> {
+    let directory = document.parent().unwrap_or(Path::new(""));
+    let root_directory = normalise(&directory.join(root));
+    let document = normalise(document);
+
+    let Ok(relative) = document.strip_prefix(&root_directory) else {
+        return Vec::new();
+    };
+
+    let mut segments: Vec<String> = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect();
+
+    // An index page is the page for its directory.
+    let file = segments.pop_if(|segment| !segment.starts_with("index."));
+    if file.is_none() {
+        segments.pop();
+    }
+
+    let mut directory = root.trim_end_matches('/').to_string();
+    let mut crumbs: Vec<(String, String)> = segments
+        .into_iter()
+        .map(|segment| {
+            directory = format!("{directory}/{segment}");
+            (format!("{directory}/"), title_case(&segment))
+        })
+        .collect();
+
+    if let Some(file) = file {
+        let text = Path::new(&file)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        crumbs.push((format!("{directory}/{file}"), title_case(&text)));
+    }
+
+    crumbs
+}
+
+/// Turn a path segment like `release-notes` into `Release Notes`.
+fn title_case(segment: &str) -> String {
+    segment
+        .split(['-', '_', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Breadcrumbs, crumbs_from_path};
+    use crate::htmplates::{HtmplateErrorKind, ToHtml};
+
+    fn crumbs(document: &str, root: &str) -> Vec<(String, String)> {
+        crumbs_from_path(Path::new(document), root)
+    }
+
+    fn crumb(href: &str, text: &str) -> (String, String) {
+        (href.to_string(), text.to_string())
+    }
+
+    fn breadcrumbs(root: Option<&str>, children: Option<&str>) -> Breadcrumbs {
+        Breadcrumbs {
+            root: root.map(str::to_string),
+            home: None,
+            separator: None,
+            children: children.map(str::to_string),
+            document: Some("site/blog/posts/hello-world.html".into()),
+        }
+    }
+
+    #[test]
+    fn page_in_the_root() {
+        assert_eq!(
+            crumbs("site/blog/posts/hello-world.html", "./"),
+            [crumb("./hello-world.html", "Hello World")]
+        );
+        assert_eq!(crumbs("site/index.html", "./"), []);
+    }
+
+    #[test]
+    fn page_below_the_root() {
+        assert_eq!(
+            crumbs("site/blog/posts/hello-world.html", "../../"),
+            [
+                crumb("../../blog/", "Blog"),
+                crumb("../../blog/posts/", "Posts"),
+                crumb("../../blog/posts/hello-world.html", "Hello World"),
+            ]
+        );
+        assert_eq!(
+            crumbs("../site/blog/release_notes.html", ".."),
+            [
+                crumb("../blog/", "Blog"),
+                crumb("../blog/release_notes.html", "Release Notes"),
+            ]
+        );
+    }
+
+    #[test]
+    fn index_page_is_its_directory() {
+        assert_eq!(
+            crumbs("site/blog/posts/index.html", "../../"),
+            [
+                crumb("../../blog/", "Blog"),
+                crumb("../../blog/posts/", "Posts"),
+            ]
+        );
+    }
+
+    #[test]
+    fn root_relative_to_the_document() {
+        for root in ["/", "/site/", "https://example.com/"] {
+            assert!(matches!(
+                breadcrumbs(Some(root), None).to_html(),
+                Err(HtmplateErrorKind::InvalidAttribute { attribute, .. }) if attribute == "root"
+            ));
+        }
+
+        let html = breadcrumbs(Some("../../"), None).to_html().unwrap();
+        assert!(html.contains(r#"<a href="../../blog/posts/" >Posts</a>"#));
+        assert!(
+            html.contains(r#"<a href="../../blog/posts/hello-world.html" aria-current="page">"#)
+        );
+    }
+
+    #[test]
+    fn children_without_links() {
+        let html = breadcrumbs(None, Some("<abbr>HTML</abbr><aside>aside</aside>"))
+            .to_html()
+            .unwrap();
+        assert!(html.contains(r#"<a href="./" >Home</a>"#));
+        assert!(!html.contains("abbr"));
+
+        let html = breadcrumbs(
+            None,
+            Some(r#"<a href="/">Home</a><a href="/blog/">Blog</a>"#),
+        )
+        .to_html()
+        .unwrap();
+        assert!(html.contains(r#"<li><a href="/blog/" aria-current="page">Blog</a></li>"#));
+    }
+}
@@ -0,0 +1,97 @@
+use htmplate_derive::HtmplateElement;
+
+use crate::{
+    htmplates::{AlertStyle, HtmplateErrorKind, ToHtml},
+    icon::Icon,
+    slot::take_slot,
+};
+
+use crate as htmplate;
+
+#[derive(HtmplateElement)]
+/// a panel with an optional header and footer
+pub struct Card {
+    /// this should be the card title
+    pub title: Option<String>,
+    /// this should be an identifier for a filled ionicon https://ionic.io/ionicons
+    pub icon: Option<Icon>,
+    /// this should be one of [error, warning, success, info, basic], defaults to basic
+    pub status: Option<AlertStyle>,
+    /// the body of the card, elements with `slot="footer"` are placed in the card's footer
+    #[htmplate(children)]
+    pub children: Option<String>,
+}
+
+impl ToHtml for Card {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            title,
+            icon,
+            status,
+            children,
+        } = self;
+
+        let (body, footer) = take_slot(&children.unwrap_or_default(), "footer")
+            .map_err(HtmplateErrorKind::invalid_children)?;
+
+        let header = if title.is_some() || icon.is_some() {
+            format!(
+                r#"<header class="card-header">{icon}<h3>{title}</h3></header>"#,
+                icon = icon.map(|icon| icon.svg()).unwrap_or_default(),
+                title = title.unwrap_or_default(),
+            )
+        } else {
+            String::new()
+        };
+
+        let footer = if footer.trim().is_empty() {
+            String::new()
+        } else {
+            format!(r#"<footer class="card-footer">{footer}</footer>"#)
+        };
+
+        Ok(format!(
+            include_str!("template.html"),
+            style = status.map(|status| status.class()).unwrap_or_default(),
+            header = header,
+            body = body,
+            footer = footer,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{replace_index, write_files};
+
+    #[test]
+    fn header_body_and_footer() {
+        let directory = write_files(
+            "card",
+            &[(
+                "index.html",
+                r#"<htmplate:card title="Plan" status="info"><p>Body</p><button slot="footer">Buy</button></htmplate:card>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains(r#"<section class="card info">"#));
+        assert!(html.contains(r#"<header class="card-header"><h3>Plan</h3></header>"#));
+        assert!(html.contains("<div class=\"card-body\">\n<p>Body</p>"));
+        assert!(html.contains(
+            r#"<footer class="card-footer"><button slot="footer">Buy</button></footer>"#
+        ));
+    }
+
+    #[test]
+    fn body_only() {
+        let directory = write_files(
+            "card-body",
+            &[("index.html", "<htmplate:card><p>Body</p></htmplate:card>")],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(!html.contains("card-header"));
+        assert!(!html.contains("card-footer"));
+    }
+}
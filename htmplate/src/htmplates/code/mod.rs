@@ -0,0 +1,248 @@
+use std::{fs, path::PathBuf, sync::LazyLock};
+
+use htmplate_derive::HtmplateElement;
+use syntect::{
+    html::{ClassStyle, ClassedHTMLGenerator},
+    parsing::{SyntaxReference, SyntaxSet},
+    util::LinesWithEndings,
+};
+
+use crate::{
+    htmplates::{HtmplateErrorKind, ToHtml},
+    icon::Icon,
+};
+
+use crate as htmplate;
+
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+#[derive(HtmplateElement)]
+/// a code block that is highlighted when templating, from the children or a file
+pub struct Code {
+    /// this should be the language of the code, defaults to the extension of `src`
+    pub lang: Option<String>,
+    /// this should be the path to a file containing the code, relative to this document
+    #[htmplate(path)]
+    pub src: Option<PathBuf>,
+    /// this should be "true" to show line numbers
+    pub line_numbers: Option<bool>,
+    /// this should be "false" to hide the copy button, defaults to true
+    pub copy: Option<bool>,
+    /// the code, used if there is no `src`, `&lt;`, `&gt;`, `&quot;`, `&#39;` and `&amp;` are decoded
+    #[htmplate(children, raw)]
+    pub children: Option<String>,
+}
+
+impl ToHtml for Code {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            lang,
+            src,
+            line_numbers,
+            copy,
+            children,
+        } = self;
+
+        let code = match &src {
+            Some(src) => fs::read_to_string(src)
+                .map_err(|source| HtmplateErrorKind::read_file(src, source))?,
+            None => dedent(&decode_entities(&children.unwrap_or_default())),
+        };
+
+        let lang = lang.or_else(|| {
+            src.as_ref()
+                .and_then(|src| src.extension())
+                .map(|extension| extension.to_string_lossy().to_string())
+        });
+
+        let syntax = match &lang {
+            Some(lang) => find_syntax(lang).ok_or_else(|| {
+                HtmplateErrorKind::invalid_attribute("lang", "this should be a supported language")
+            })?,
+            None => plain_text_syntax(),
+        };
+
+        code_block(
+            &code,
+            lang.as_deref(),
+            syntax,
+            line_numbers.is_some_and(|line_numbers| line_numbers),
+            copy.is_none_or(|copy| copy),
+        )
+    }
+}
+
+/// Highlight some code into the HTML of a code block.
+pub(crate) fn code_block(
+    code: &str,
+    lang: Option<&str>,
+    syntax: &SyntaxReference,
+    line_numbers: bool,
+    copy: bool,
+) -> Result<String, HtmplateErrorKind> {
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(
+        syntax,
+        &SYNTAXES,
+        ClassStyle::SpacedPrefixed { prefix: "hl-" },
+    );
+    for line in LinesWithEndings::from(code.trim_end()) {
+        generator
+            .parse_html_for_line_which_includes_newline(line)
+            .map_err(|_| {
+                HtmplateErrorKind::invalid_attribute("lang", "the code could not be highlighted")
+            })?;
+    }
+    let highlighted = preserve_whitespace(&generator.finalize());
+
+    let line_numbers = if line_numbers {
+        let numbers: Vec<String> = (1..=code.trim_end().lines().count().max(1))
+            .map(|number| number.to_string())
+            .collect();
+        format!(
+            r#"<span class="line-numbers" aria-hidden="true">{}</span>"#,
+            numbers.join("&#10;")
+        )
+    } else {
+        String::new()
+    };
+
+    let copy_button = if copy {
+        format!(
+            r#"<button class="copy circle ghost" type="button" aria-label="Copy code">{}</button>"#,
+            Icon::Copy.svg()
+        )
+    } else {
+        String::new()
+    };
+
+    Ok(format!(
+        include_str!("template.html"),
+        lang = lang.unwrap_or("text"),
+        line_numbers = line_numbers,
+        code = highlighted,
+        copy_button = copy_button,
+    ))
+}
+
+/// Find the syntax for a language, if it is supported.
+pub(crate) fn find_syntax(lang: &str) -> Option<&'static SyntaxReference> {
+    SYNTAXES.find_syntax_by_token(lang)
+}
+
+/// The syntax for code that isn't highlighted.
+pub(crate) fn plain_text_syntax() -> &'static SyntaxReference {
+    SYNTAXES.find_syntax_plain_text()
+}
+
+/// Encode the whitespace in the text of some HTML so it survives collapsing the whitespace of the
+/// templated document.
+fn preserve_whitespace(html: &str) -> String {
+    let mut preserved = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for char in html.chars() {
+        match char {
+            '<' => {
+                in_tag = true;
+                preserved.push(char);
+            }
+            '>' => {
+                in_tag = false;
+                preserved.push(char);
+            }
+            ' ' if !in_tag => preserved.push_str("&#32;"),
+            '\t' if !in_tag => preserved.push_str("&#9;"),
+            '\n' if !in_tag => preserved.push_str("&#10;"),
+            '\r' if !in_tag => {}
+            char => preserved.push(char),
+        }
+    }
+
+    preserved
+}
+
+fn decode_entities(code: &str) -> String {
+    code.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Remove the blank lines around some code and the indentation shared by its lines.
+fn dedent(code: &str) -> String {
+    let lines: Vec<&str> = code
+        .lines()
+        .skip_while(|line| line.trim().is_empty())
+        .collect();
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |index| index + 1);
+    let lines = &lines[..end];
+
+    // the indentation is compared as text so tabs and spaces aren't mistaken for each other
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .reduce(|indent, line_indent| {
+            let shared = indent
+                .char_indices()
+                .zip(line_indent.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or(indent.len().min(line_indent.len()), |((index, _), _)| index);
+            &indent[..shared]
+        })
+        .unwrap_or_default();
+
+    lines
+        .iter()
+        .map(|line| line.strip_prefix(indent).unwrap_or(line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_entities, dedent, preserve_whitespace};
+
+    #[test]
+    fn dedent_blank_first_and_last_lines() {
+        assert_eq!(
+            dedent("\n  \n    fn main() {\n        run();\n\n    }\n  \n"),
+            "fn main() {\n    run();\n\n}"
+        );
+    }
+
+    #[test]
+    fn dedent_tabs_mixed_with_spaces() {
+        assert_eq!(dedent("\ta\n\t\tb\n\t    c"), "a\n\tb\n    c");
+        assert_eq!(dedent("\ta\n    b"), "\ta\n    b");
+        assert_eq!(dedent("  \ta\n  b"), "\ta\nb");
+    }
+
+    #[test]
+    fn dedent_nothing() {
+        assert_eq!(dedent(""), "");
+        assert_eq!(dedent("\n  \n"), "");
+    }
+
+    #[test]
+    fn decode_entities_once() {
+        assert_eq!(
+            decode_entities("if a &lt; b &amp;&amp; c &gt; d { &quot;&#39;&quot; }"),
+            r#"if a < b && c > d { "'" }"#
+        );
+        assert_eq!(decode_entities("&amp;lt;p&amp;gt;"), "&lt;p&gt;");
+        assert_eq!(decode_entities("&amp;amp;"), "&amp;");
+    }
+
+    #[test]
+    fn preserve_whitespace_outside_tags() {
+        assert_eq!(
+            preserve_whitespace("<span class=\"hl-a b\">a\tb</span>\r\n c"),
+            "<span class=\"hl-a b\">a&#9;b</span>&#10;&#32;c"
+        );
+    }
+}
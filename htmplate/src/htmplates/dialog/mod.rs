@@ -0,0 +1,53 @@
+use htmplate_derive::HtmplateElement;
+
+use crate::{
+    htmplates::{HtmplateErrorKind, ToHtml},
+    icon::Icon,
+    slot::take_slot,
+};
+
+use crate as htmplate;
+
+#[derive(HtmplateElement)]
+/// a modal dialog, opened by an icon-button with a matching `dialog` attribute
+pub struct Dialog {
+    /// this should be the ID of the dialog
+    pub id: String,
+    /// this should be the dialog title
+    pub title: String,
+    /// this should be an identifier for a filled ionicon https://ionic.io/ionicons
+    pub icon: Option<Icon>,
+    /// the body of the dialog, elements with `slot="actions"` are placed at the bottom of the dialog
+    #[htmplate(children)]
+    pub children: Option<String>,
+}
+
+impl ToHtml for Dialog {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            id,
+            title,
+            icon,
+            children,
+        } = self;
+
+        let (body, actions) = take_slot(&children.unwrap_or_default(), "actions")
+            .map_err(HtmplateErrorKind::invalid_children)?;
+
+        let actions = if actions.trim().is_empty() {
+            String::new()
+        } else {
+            format!(r#"<footer class="dialog-actions">{actions}</footer>"#)
+        };
+
+        Ok(format!(
+            include_str!("template.html"),
+            id = id,
+            title = title,
+            icon = icon.map(|icon| icon.svg()).unwrap_or_default(),
+            close_icon = Icon::Close.svg(),
+            body = body,
+            actions = actions,
+        ))
+    }
+}
@@ -1,19 +1,83 @@
+use chrono::{Datelike, Utc};
 use htmplate_derive::HtmplateElement;
 
 use crate::{
-    htmplates::{HtmplateErrorKind, ToHtml},
+    htmplates::{HtmplateErrorKind, Link, ToHtml},
     icon::Icon,
 };
 
 use crate as htmplate;
 
+const DEFAULT_AUTHOR: &str = "Trent Shailer";
+const DEFAULT_AUTHOR_HREF: &str = "https://github.com/trentshailer";
+
 #[derive(HtmplateElement)]
 /// the shared footer
-pub struct Footer;
+pub struct Footer {
+    /// this should be who made the site, an empty author hides the author link, defaults to "Trent Shailer"
+    pub author: Option<String>,
+    /// this should be where the author links to
+    pub author_href: Option<String>,
+    /// this should be an identifier for a filled ionicon https://ionic.io/ionicons shown before the author
+    pub author_icon: Option<Icon>,
+    /// this should be a list of links, one per line, each either `href|text` or `href|text|icon`
+    #[htmplate(separator = "\n")]
+    pub links: Vec<Link>,
+    /// this should be the copyright holder, shows a copyright line if set
+    pub copyright: Option<String>,
+    /// this should be the year or years of the copyright, defaults to the current year
+    pub year: Option<String>,
+}
 impl ToHtml for Footer {
     fn to_html(self) -> Result<String, HtmplateErrorKind> {
-        let icon = Icon::LogoGithub.svg();
+        let Self {
+            author,
+            author_href,
+            author_icon,
+            links,
+            copyright,
+            year,
+        } = self;
+
+        let mut content = Vec::new();
+
+        let is_default_author = author.is_none();
+        let author = author.unwrap_or_else(|| DEFAULT_AUTHOR.to_string());
+        if !author.is_empty() {
+            let icon = author_icon.map(|icon| icon.svg()).unwrap_or_default();
+
+            let author = match author_href {
+                Some(href) => format!(
+                    include_str!("author.template.html"),
+                    href = href,
+                    aria_label_attribute = "",
+                    icon = icon,
+                    author = author,
+                ),
+                None if is_default_author => format!(
+                    include_str!("author.template.html"),
+                    href = DEFAULT_AUTHOR_HREF,
+                    aria_label_attribute = r#"aria-label="Link to Trent Shailer's Git Hub""#,
+                    icon = author_icon.unwrap_or(Icon::LogoGithub).svg(),
+                    author = author,
+                ),
+                None => format!(r#"<span class="author">{icon}Made by {author}</span>"#),
+            };
+            content.push(author);
+        }
+
+        content.extend(links.iter().map(|link| link.to_html("")));
+
+        if let Some(copyright) = copyright {
+            let year = year.unwrap_or_else(|| Utc::now().year().to_string());
+            content.push(format!(
+                r#"<small class="copyright">© {year} {copyright}</small>"#
+            ));
+        }
 
-        Ok(format!(include_str!("template.html"), icon = icon))
+        Ok(format!(
+            include_str!("template.html"),
+            content = content.join("\n")
+        ))
     }
 }
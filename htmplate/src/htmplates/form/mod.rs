@@ -1,15 +1,24 @@
 mod alert;
 mod check_input;
+mod radio_group;
+mod select;
 mod submit;
 mod text_input;
+mod textarea;
+mod wrapper;
 
 use core::str::FromStr;
 
 pub use alert::FormAlert;
 pub use check_input::FormCheckInput;
-pub use submit::FormSubmit;
-pub use text_input::FormTextInput;
+pub use radio_group::FormRadioGroup;
+pub use select::{FormSelect, SelectOption};
+pub use submit::{ButtonVariant, FormSubmit};
+pub use text_input::{FormTextInput, InputType};
+pub use textarea::FormTextarea;
+pub use wrapper::Form;
 
+/// The ID of a form or an input, must start with a `/`.
 #[derive(Debug, Clone)]
 pub struct FormId(pub String);
 impl FromStr for FormId {
@@ -0,0 +1,140 @@
+use htmplate_derive::HtmplateElement;
+
+use crate::{
+    self as htmplate,
+    htmplates::{HtmplateErrorKind, ToHtml, form::FormId, form::SelectOption},
+};
+
+#[derive(HtmplateElement)]
+/// a group of radio buttons for a form
+pub struct FormRadioGroup {
+    /// this should be the id of the input, must start with a `/`
+    pub input: FormId,
+    /// this should be the id of the form, must start with a `/`
+    pub form: FormId,
+    /// this should be the legend for the group
+    pub label: String,
+    /// this should be "true" if the input required
+    pub required: Option<bool>,
+    /// this should be a comma separated list of options, each either `value` or `value=label`
+    #[htmplate(separator = ",")]
+    pub options: Vec<SelectOption>,
+    /// this should be the value of the option that is selected initially
+    pub value: Option<String>,
+}
+
+impl ToHtml for FormRadioGroup {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            input: FormId(id),
+            form: FormId(form),
+            label,
+            required,
+            options,
+            value,
+        } = self;
+
+        if options.is_empty() {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "options",
+                "there must be at least one option",
+            ));
+        }
+        if let Some(value) = &value
+            && !options.iter().any(|option| &option.value == value)
+        {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "value",
+                "this should be the value of one of the options",
+            ));
+        }
+
+        let is_required = required.is_some_and(|required| required);
+        let required_marker = if is_required {
+            r#"<span aria-hidden="true"><strong>*</strong></span>"#
+        } else {
+            ""
+        };
+
+        let required_attribute = if is_required { "required" } else { "" };
+
+        let label_id = format!("{form}{id}/label");
+        let input_id = format!("{form}{id}/input");
+        let error_id = format!("{form}{id}/error");
+
+        let options: String = options
+            .into_iter()
+            .enumerate()
+            .map(|(index, option)| {
+                let checked_attribute = if value.as_ref() == Some(&option.value) {
+                    "checked"
+                } else {
+                    ""
+                };
+
+                format!(
+                    include_str!("radio_option.html"),
+                    option_id = format!("{input_id}/{index}"),
+                    input_id = input_id,
+                    value = option.value,
+                    label = option.label,
+                    required_attribute = required_attribute,
+                    checked_attribute = checked_attribute,
+                )
+            })
+            .collect();
+
+        Ok(format!(
+            include_str!("radio_group.html"),
+            label_id = label_id,
+            input_id = input_id,
+            error_id = error_id,
+            label = label,
+            required_marker = required_marker,
+            options = options,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{error_messages, replace_index, write_files};
+
+    #[test]
+    fn fieldset_and_legend() {
+        let directory = write_files(
+            "form-radio-group",
+            &[(
+                "index.html",
+                r#"<htmplate:form-radio-group input="/size" form="/order" label="Size" options="s=Small, m=Medium" value="m"/>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains(
+            r#"<fieldset class="radio-group" id="/order/size/input" aria-labelledby="/order/size/label">"#
+        ));
+        assert!(html.contains(r#"<legend id="/order/size/label">"#));
+        assert!(html.contains(r#"id="/order/size/input/0" name="/order/size/input" value="s""#));
+        assert_eq!(html.matches("checked").count(), 1);
+        assert!(html.contains(r#"value="m" checked"#));
+    }
+
+    #[test]
+    fn value_not_an_option() {
+        let directory = write_files(
+            "form-radio-group-value",
+            &[(
+                "index.html",
+                r#"<htmplate:form-radio-group input="/size" form="/order" label="Size" options="s, m" value="l"/>"#,
+            )],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("the value of one of the options"))
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use core::str::FromStr;
+
+use htmplate_derive::HtmplateElement;
+
+use crate::{
+    self as htmplate,
+    htmplates::{HtmplateErrorKind, ToHtml, form::FormId},
+};
+
+/// An option in a select.
+#[derive(Debug, Clone)]
+pub struct SelectOption {
+    /// The value of the option.
+    pub value: String,
+    /// The text shown for the option.
+    pub label: String,
+}
+impl FromStr for SelectOption {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, label) = s.split_once('=').unwrap_or((s, s));
+        let (value, label) = (value.trim(), label.trim());
+
+        if value.is_empty() || label.is_empty() {
+            return Err(());
+        }
+
+        Ok(Self {
+            value: value.to_string(),
+            label: label.to_string(),
+        })
+    }
+}
+
+#[derive(HtmplateElement)]
+/// a select input for a form
+pub struct FormSelect {
+    /// this should be the id of the input, must start with a `/`
+    pub input: FormId,
+    /// this should be the id of the form, must start with a `/`
+    pub form: FormId,
+    /// this should be the input label contents
+    pub label: String,
+    /// this should be "true" if the input required
+    pub required: Option<bool>,
+    /// this should be the text shown before an option is selected
+    pub placeholder: Option<String>,
+    /// this should be a comma separated list of options, each either `value` or `value=label`
+    #[htmplate(separator = ",")]
+    pub options: Vec<SelectOption>,
+    /// the `<option>` elements of the select, these come after any options from the attribute
+    #[htmplate(children)]
+    pub children: Option<String>,
+}
+
+impl ToHtml for FormSelect {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            input: FormId(id),
+            form: FormId(form),
+            label,
+            required,
+            placeholder,
+            options,
+            children,
+        } = self;
+
+        let is_required = required.is_some_and(|required| required);
+        let required_marker = if is_required {
+            r#"<span aria-hidden="true"><strong>*</strong></span>"#
+        } else {
+            ""
+        };
+
+        let required_attribute = if is_required { "required" } else { "" };
+
+        let placeholder = placeholder
+            .map(|placeholder| {
+                format!(r#"<option value="" disabled selected hidden>{placeholder}</option>"#)
+            })
+            .unwrap_or_default();
+
+        let options: String = options
+            .into_iter()
+            .map(|SelectOption { value, label }| {
+                format!(r#"<option value="{value}">{label}</option>"#)
+            })
+            .chain(children)
+            .collect();
+
+        if options.trim().is_empty() {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "options",
+                "there must be at least one option from the attribute or the children",
+            ));
+        }
+
+        let label_id = format!("{form}{id}/label");
+        let input_id = format!("{form}{id}/input");
+        let error_id = format!("{form}{id}/error");
+
+        Ok(format!(
+            include_str!("select.html"),
+            label_id = label_id,
+            input_id = input_id,
+            error_id = error_id,
+            label = label,
+            required_marker = required_marker,
+            required_attribute = required_attribute,
+            placeholder = placeholder,
+            options = options,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{error_messages, replace_index, write_files};
+
+    #[test]
+    fn options_from_the_attribute_then_the_children() {
+        let directory = write_files(
+            "form-select",
+            &[(
+                "index.html",
+                r#"<htmplate:form-select input="/colour" form="/settings" label="Colour" placeholder="Pick one" options="red=Red, blue">
+  <option value="green">Green</option>
+</htmplate:form-select>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains(r#"<select id="/settings/colour/input""#));
+        assert!(html.contains(r#"<option value="" disabled selected hidden>Pick one</option>"#));
+        let red = html.find(r#"<option value="red">Red</option>"#).unwrap();
+        let blue = html.find(r#"<option value="blue">blue</option>"#).unwrap();
+        let green = html
+            .find(r#"<option value="green">Green</option>"#)
+            .unwrap();
+        assert!(red < blue && blue < green);
+    }
+
+    #[test]
+    fn without_options() {
+        let directory = write_files(
+            "form-select-empty",
+            &[(
+                "index.html",
+                r#"<htmplate:form-select input="/colour" form="/settings" label="Colour">
+</htmplate:form-select>"#,
+            )],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(messages.iter().any(|message| {
+            message.contains("there must be at least one option from the attribute or the children")
+        }));
+    }
+}
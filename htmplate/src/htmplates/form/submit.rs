@@ -1,21 +1,112 @@
+use core::str::FromStr;
+
 use htmplate_derive::HtmplateElement;
 
 use crate::{
     self as htmplate,
     htmplates::{HtmplateErrorKind, ToHtml, form::FormId},
+    icon::Icon,
 };
 
+/// The variant of a button.
+#[derive(Clone, Debug)]
+#[allow(missing_docs)]
+pub enum ButtonVariant {
+    Primary,
+    Ghost,
+    Danger,
+}
+impl ButtonVariant {
+    /// The class for the variant.
+    pub fn class(&self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Ghost => "ghost",
+            Self::Danger => "danger",
+        }
+    }
+}
+impl FromStr for ButtonVariant {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "primary" => Ok(Self::Primary),
+            "ghost" => Ok(Self::Ghost),
+            "danger" => Ok(Self::Danger),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(HtmplateElement)]
 /// the submit button for a form
 pub struct FormSubmit {
     /// this should be the ID of the form this submits, must start with a `/`
     pub form: FormId,
+    /// this should be the button text, defaults to "Submit"
+    pub text: Option<String>,
+    /// this should be an identifier for a filled ionicon https://ionic.io/ionicons
+    pub icon: Option<Icon>,
+    /// this should be one of [primary, ghost, danger], the button is unstyled by default
+    pub variant: Option<ButtonVariant>,
 }
 
 impl ToHtml for FormSubmit {
     fn to_html(self) -> Result<String, HtmplateErrorKind> {
-        let Self { form: FormId(form) } = self;
+        let Self {
+            form: FormId(form),
+            text,
+            icon,
+            variant,
+        } = self;
+
+        Ok(format!(
+            include_str!("submit.html"),
+            form = form,
+            variant_class = variant
+                .map(|variant| format!(r#"class="{}""#, variant.class()))
+                .unwrap_or_default(),
+            icon = icon.map(|icon| icon.svg()).unwrap_or_default(),
+            text = text.as_deref().unwrap_or("Submit"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{replace_index, write_files};
+
+    #[test]
+    fn busy_spinner() {
+        let directory = write_files(
+            "form-submit",
+            &[(
+                "index.html",
+                r#"<htmplate:form-submit form="/login" text="Log in"/>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains(r#"<button id="/login/submit""#));
+        assert!(html.contains(r#"type="submit" aria-busy="false">"#));
+        assert!(!html.contains("class=\"danger\""));
+        assert!(html.contains(r#"<svg class="spinner""#));
+        assert!(html.contains("<span>Log in</span>"));
+    }
+
+    #[test]
+    fn variant() {
+        let directory = write_files(
+            "form-submit-variant",
+            &[(
+                "index.html",
+                r#"<htmplate:form-submit form="/login" variant="danger"/>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
 
-        Ok(format!(include_str!("submit.html"), form = form))
+        assert!(html.contains(r#"<button id="/login/submit" class="danger" type="submit""#));
+        assert!(html.contains("<span>Submit</span>"));
     }
 }
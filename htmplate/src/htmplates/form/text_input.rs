@@ -1,10 +1,63 @@
+use core::str::FromStr;
+
 use htmplate_derive::HtmplateElement;
 
 use crate::{
     self as htmplate,
     htmplates::{HtmplateErrorKind, ToHtml, form::FormId},
+    icon::Icon,
 };
 
+/// The type of a text input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum InputType {
+    Text,
+    Email,
+    Password,
+    Number,
+    Url,
+    Tel,
+    Date,
+    Search,
+}
+impl InputType {
+    /// The value of the input's `type` attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Email => "email",
+            Self::Password => "password",
+            Self::Number => "number",
+            Self::Url => "url",
+            Self::Tel => "tel",
+            Self::Date => "date",
+            Self::Search => "search",
+        }
+    }
+
+    fn has_range(&self) -> bool {
+        matches!(self, Self::Number | Self::Date)
+    }
+}
+impl FromStr for InputType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "email" => Ok(Self::Email),
+            "password" => Ok(Self::Password),
+            "number" => Ok(Self::Number),
+            "url" => Ok(Self::Url),
+            "tel" => Ok(Self::Tel),
+            "date" => Ok(Self::Date),
+            "search" => Ok(Self::Search),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(HtmplateElement)]
 /// a text input for a form
 pub struct FormTextInput {
@@ -18,6 +71,22 @@ pub struct FormTextInput {
     pub required: Option<bool>,
     /// this should be "true" if the text input for a credential-like field
     pub credential: Option<bool>,
+    /// this should be one of [text, email, password, number, url, tel, date, search], defaults to text
+    pub input_type: Option<InputType>,
+    /// this should be the minimum number of characters, defaults to 4 for credentials
+    pub minlength: Option<u32>,
+    /// this should be the maximum number of characters, defaults to 64 for credentials
+    pub maxlength: Option<u32>,
+    /// this should be a regular expression the value must match
+    pub pattern: Option<String>,
+    /// this should be the minimum value of a number or date input
+    pub min: Option<String>,
+    /// this should be the maximum value of a number or date input
+    pub max: Option<String>,
+    /// this should be the autocomplete hint, defaults to "off" for credentials
+    pub autocomplete: Option<String>,
+    /// this should be the placeholder text, defaults to the label
+    pub placeholder: Option<String>,
 }
 
 impl ToHtml for FormTextInput {
@@ -28,8 +97,48 @@ impl ToHtml for FormTextInput {
             required,
             label,
             credential,
+            input_type,
+            minlength,
+            maxlength,
+            pattern,
+            min,
+            max,
+            autocomplete,
+            placeholder,
         } = self;
 
+        let input_type = input_type.unwrap_or(InputType::Text);
+
+        if !input_type.has_range() {
+            if min.is_some() {
+                return Err(HtmplateErrorKind::invalid_attribute(
+                    "min",
+                    "this is only valid for number and date inputs",
+                ));
+            }
+            if max.is_some() {
+                return Err(HtmplateErrorKind::invalid_attribute(
+                    "max",
+                    "this is only valid for number and date inputs",
+                ));
+            }
+        }
+
+        let is_credential = credential.is_some_and(|credential| credential);
+        let minlength = minlength.or(is_credential.then_some(4));
+        let maxlength = maxlength.or(is_credential.then_some(64));
+        let autocomplete = autocomplete.or(is_credential.then(|| "off".to_string()));
+
+        if let Some(minlength) = minlength
+            && let Some(maxlength) = maxlength
+            && minlength > maxlength
+        {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "minlength",
+                "this should be less than or equal to `maxlength`",
+            ));
+        }
+
         let is_required = required.is_some_and(|required| required);
         let required_marker = if is_required {
             r#"<span aria-hidden="true"><strong>*</strong></span>"#
@@ -40,19 +149,56 @@ impl ToHtml for FormTextInput {
         let required_attribute = if is_required { "required" } else { "" };
 
         let mut extra_attributes: Vec<String> = Vec::new();
-        if let Some(credential) = credential
-            && credential
-        {
-            extra_attributes.push(r#"minlength="4""#.to_string());
-            extra_attributes.push(r#"maxlength="64""#.to_string());
+        if let Some(minlength) = minlength {
+            extra_attributes.push(format!(r#"minlength="{minlength}""#));
+        }
+        if let Some(maxlength) = maxlength {
+            extra_attributes.push(format!(r#"maxlength="{maxlength}""#));
+        }
+        if let Some(pattern) = pattern {
+            extra_attributes.push(format!(r#"pattern="{pattern}""#));
+        }
+        if let Some(min) = min {
+            extra_attributes.push(format!(r#"min="{min}""#));
+        }
+        if let Some(max) = max {
+            extra_attributes.push(format!(r#"max="{max}""#));
+        }
+        if is_credential {
             extra_attributes.push(r#"autocapitalize="off""#.to_string());
-            extra_attributes.push(r#"autocomplete="off""#.to_string());
+        }
+        if let Some(autocomplete) = autocomplete {
+            extra_attributes.push(format!(r#"autocomplete="{autocomplete}""#));
         }
         let extra_attributes = extra_attributes.join("\n");
 
         let label_id = format!("{form}{id}/label");
         let input_id = format!("{form}{id}/input");
         let error_id = format!("{form}{id}/error");
+        let visibility_id = format!("{form}{id}/visibility");
+
+        let input = format!(
+            include_str!("text_input_field.html"),
+            input_id = input_id,
+            input_type = input_type.as_str(),
+            placeholder = placeholder.as_ref().unwrap_or(&label),
+            required_attribute = required_attribute,
+            extra_attributes = extra_attributes,
+        );
+
+        // Password inputs are grouped with a button that toggles their visibility.
+        let input = if input_type == InputType::Password {
+            format!(
+                include_str!("password_group.html"),
+                input = input,
+                visibility_id = visibility_id,
+                input_id = input_id,
+                show_icon = Icon::Eye.svg(),
+                hide_icon = Icon::EyeOff.svg(),
+            )
+        } else {
+            input
+        };
 
         Ok(format!(
             include_str!("text_input.html"),
@@ -61,8 +207,66 @@ impl ToHtml for FormTextInput {
             error_id = error_id,
             label = label,
             required_marker = required_marker,
-            required_attribute = required_attribute,
-            extra_attributes = extra_attributes,
+            input = input,
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{error_messages, replace_index, write_files};
+
+    #[test]
+    fn type_and_constraints() {
+        let directory = write_files(
+            "form-text-input",
+            &[(
+                "index.html",
+                r#"<htmplate:form-text-input input="/age" form="/profile" label="Age" input-type="number" min="0" max="150" required="true"/>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains(r#"type="number""#));
+        assert!(html.contains(r#"min="0""#));
+        assert!(html.contains(r#"max="150""#));
+        assert!(html.contains("required"));
+        assert!(!html.contains("input-group"));
+    }
+
+    #[test]
+    fn password_visibility_toggle() {
+        let directory = write_files(
+            "form-text-input-password",
+            &[(
+                "index.html",
+                r#"<htmplate:form-text-input input="/password" form="/login" label="Password" input-type="password" credential="true"/>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains(
+            "<div class=\"input-group\">\n<input id=\"/login/password/input\" type=\"password\""
+        ));
+        assert!(html.contains(r#"minlength="4""#));
+        assert!(html.contains(r#"aria-controls="/login/password/input""#));
+    }
+
+    #[test]
+    fn range_on_a_text_input() {
+        let directory = write_files(
+            "form-text-input-range",
+            &[(
+                "index.html",
+                r#"<htmplate:form-text-input input="/name" form="/profile" label="Name" min="1"/>"#,
+            )],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("only valid for number and date inputs"))
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use htmplate_derive::HtmplateElement;
+
+use crate::{
+    self as htmplate,
+    htmplates::{HtmplateErrorKind, ToHtml, form::FormId},
+};
+
+#[derive(HtmplateElement)]
+/// a multi-line text input for a form
+pub struct FormTextarea {
+    /// this should be the id of the input, must start with a `/`
+    pub input: FormId,
+    /// this should be the id of the form, must start with a `/`
+    pub form: FormId,
+    /// this should be the input label contents
+    pub label: String,
+    /// this should be "true" if the input required
+    pub required: Option<bool>,
+    /// this should be the number of visible lines, defaults to 3
+    pub rows: Option<u32>,
+    /// this should be the minimum number of characters
+    pub minlength: Option<u32>,
+    /// this should be the maximum number of characters
+    pub maxlength: Option<u32>,
+    /// this should be "true" if a live character counter is shown
+    pub counter: Option<bool>,
+}
+
+impl ToHtml for FormTextarea {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            input: FormId(id),
+            form: FormId(form),
+            label,
+            required,
+            rows,
+            minlength,
+            maxlength,
+            counter,
+        } = self;
+
+        if let Some(minlength) = minlength
+            && let Some(maxlength) = maxlength
+            && minlength > maxlength
+        {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "minlength",
+                "this should be less than or equal to `maxlength`",
+            ));
+        }
+
+        let is_required = required.is_some_and(|required| required);
+        let required_marker = if is_required {
+            r#"<span aria-hidden="true"><strong>*</strong></span>"#
+        } else {
+            ""
+        };
+
+        let required_attribute = if is_required { "required" } else { "" };
+
+        let mut extra_attributes: Vec<String> = Vec::new();
+        if let Some(minlength) = minlength {
+            extra_attributes.push(format!(r#"minlength="{minlength}""#));
+        }
+        if let Some(maxlength) = maxlength {
+            extra_attributes.push(format!(r#"maxlength="{maxlength}""#));
+        }
+        let extra_attributes = extra_attributes.join("\n");
+
+        let label_id = format!("{form}{id}/label");
+        let input_id = format!("{form}{id}/input");
+        let error_id = format!("{form}{id}/error");
+        let counter_id = format!("{form}{id}/counter");
+
+        let counter = if counter.is_some_and(|counter| counter) {
+            let count = match maxlength {
+                Some(maxlength) => format!("0/{maxlength}"),
+                None => "0".to_string(),
+            };
+            format!(
+                r#"<small class="counter" id="{counter_id}" aria-live="polite">{count}</small>"#
+            )
+        } else {
+            String::new()
+        };
+
+        Ok(format!(
+            include_str!("textarea.html"),
+            label_id = label_id,
+            input_id = input_id,
+            error_id = error_id,
+            label = label,
+            rows = rows.unwrap_or(3),
+            required_marker = required_marker,
+            required_attribute = required_attribute,
+            extra_attributes = extra_attributes,
+            counter = counter,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{error_messages, replace_index, write_files};
+
+    #[test]
+    fn counter() {
+        let directory = write_files(
+            "form-textarea",
+            &[(
+                "index.html",
+                r#"<htmplate:form-textarea input="/bio" form="/profile" label="Bio" rows="5" maxlength="200" counter="true"/>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains(r#"rows="5""#));
+        assert!(html.contains(r#"maxlength="200""#));
+        assert!(html.contains(
+            r#"<small class="counter" id="/profile/bio/counter" aria-live="polite">0/200</small>"#
+        ));
+    }
+
+    #[test]
+    fn minlength_over_maxlength() {
+        let directory = write_files(
+            "form-textarea-length",
+            &[(
+                "index.html",
+                r#"<htmplate:form-textarea input="/bio" form="/profile" label="Bio" minlength="10" maxlength="5"/>"#,
+            )],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("less than or equal to `maxlength`"))
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use htmplate_derive::HtmplateElement;
+
+use crate::{
+    self as htmplate, AttributeSource,
+    attribute_source::ElementAttributes,
+    context::Context,
+    htmplates::{
+        HtmplateErrorKind, ToHtml,
+        form::{ButtonVariant, FormAlert, FormId, FormSubmit},
+    },
+    icon::Icon,
+};
+
+#[derive(HtmplateElement)]
+/// a form with an alert and a submit button, the htmplates inside of it inherit its ID
+pub struct Form {
+    /// this should be the ID of the form, must start with a `/`
+    pub id: FormId,
+    /// this should be what the form does, used as the form's name and in its error messages
+    pub action: String,
+    /// this should be the submit button text, defaults to "Submit"
+    pub submit_text: Option<String>,
+    /// this should be an identifier for a filled ionicon https://ionic.io/ionicons for the submit button
+    pub submit_icon: Option<Icon>,
+    /// this should be one of [primary, ghost, danger] for the submit button, which is unstyled by default
+    pub submit_variant: Option<ButtonVariant>,
+    /// the inputs of the form, an alert or submit button inside of these replaces the default one
+    #[htmplate(children)]
+    pub children: Option<String>,
+}
+impl Form {
+    /// Replace the form's children inside of the form.
+    pub(crate) fn scope(source: &ElementAttributes, context: Context) -> Context {
+        match source.attribute("id") {
+            Some(id) => context.with_form(id),
+            None => context,
+        }
+    }
+}
+
+impl ToHtml for Form {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            id: FormId(id),
+            action,
+            submit_text,
+            submit_icon,
+            submit_variant,
+            children,
+        } = self;
+
+        let children = children.unwrap_or_default();
+
+        let alert = if children.contains(&format!(r#"id="{id}/error""#)) {
+            String::new()
+        } else {
+            FormAlert {
+                form: FormId(id.clone()),
+            }
+            .to_html()?
+        };
+
+        let submit = if children.contains(&format!(r#"id="{id}/submit""#)) {
+            String::new()
+        } else {
+            FormSubmit {
+                form: FormId(id.clone()),
+                text: submit_text,
+                icon: submit_icon,
+                variant: submit_variant,
+            }
+            .to_html()?
+        };
+
+        Ok(format!(
+            include_str!("wrapper.html"),
+            id = id,
+            action = action,
+            alert = alert,
+            children = children,
+            submit = submit,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{error_messages, replace_index, write_files};
+
+    #[test]
+    fn children_inherit_the_id() {
+        let directory = write_files(
+            "form",
+            &[(
+                "index.html",
+                r#"<htmplate:form id="/login" action="Log in">
+  <htmplate:form-text-input input="/user" label="User"/>
+  <htmplate:form-text-input input="/password" form="/login" label="Password"/>
+</htmplate:form>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains(r#"<form id="/login" name="Log in">"#));
+        assert!(html.contains(r#"id="/login/user/input""#));
+        assert!(html.contains(r#"id="/login/password/input""#));
+        assert!(html.contains(r#"id="/login/error""#));
+        assert!(html.contains(r#"id="/login/submit""#));
+    }
+
+    #[test]
+    fn submit_inside_replaces_the_default() {
+        let directory = write_files(
+            "form-submit-inside",
+            &[(
+                "index.html",
+                r#"<htmplate:form id="/login" action="Log in"><htmplate:form-submit text="Go"/></htmplate:form>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert_eq!(html.matches(r#"id="/login/submit""#).count(), 1);
+        assert!(html.contains("<span>Go</span>"));
+    }
+
+    #[test]
+    fn mismatched_id() {
+        let directory = write_files(
+            "form-mismatch",
+            &[(
+                "index.html",
+                "<htmplate:form id=\"/login\" action=\"Log in\">\n  <htmplate:form-text-input input=\"/user\" form=\"/signup\" label=\"User\"/>\n</htmplate:form>",
+            )],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(messages.iter().any(|message| {
+            message.starts_with("could not template a htmplate:form-text-input at")
+                && message.contains("index.html:2:3")
+        }));
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("this should match the enclosing form `/login`"))
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::path::PathBuf;
+
+use htmplate_derive::HtmplateElement;
+
+use crate::{
+    htmplates::{HtmplateErrorKind, Link, ToHtml, title::icon_and_text},
+    icon::Icon,
+    slot::take_slot,
+};
+
+use crate as htmplate;
+
+#[derive(HtmplateElement)]
+/// the site header with navigation links that collapse into a menu on small screens
+pub struct Header {
+    /// this should be the ID of the header
+    pub id: Option<String>,
+    /// this should be the site name shown as the brand
+    pub brand: Option<String>,
+    /// this should be an identifier for a filled ionicon https://ionic.io/ionicons shown before the brand
+    pub brand_icon: Option<Icon>,
+    /// this should be where the brand links to, defaults to "/"
+    pub brand_href: Option<String>,
+    /// this should be a list of links, one per line, each either `href|text` or `href|text|icon`
+    #[htmplate(separator = "\n")]
+    pub links: Vec<Link>,
+    /// this should be the href of the current page, the matching link is highlighted, defaults to the link to this document
+    pub current: Option<String>,
+    /// extra content after the links, elements with `slot="brand"` replace the brand
+    #[htmplate(children)]
+    pub children: Option<String>,
+    /// the path of the document, used to find the current link
+    #[htmplate(document)]
+    pub document: Option<PathBuf>,
+}
+
+impl ToHtml for Header {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            id,
+            brand,
+            brand_icon,
+            brand_href,
+            links,
+            current,
+            children,
+            document,
+        } = self;
+
+        let id = id.unwrap_or_else(|| "header".to_string());
+
+        let (children, brand_slot) = take_slot(&children.unwrap_or_default(), "brand")
+            .map_err(HtmplateErrorKind::invalid_children)?;
+
+        let brand = if !brand_slot.trim().is_empty() {
+            brand_slot
+        } else if brand.is_some() || brand_icon.is_some() {
+            // A brand without text is labelled so the link still has a name.
+            let label = if brand.is_none() {
+                r#"aria-label="Home""#
+            } else {
+                ""
+            };
+            let href = brand_href.as_deref().unwrap_or("/");
+            let content = icon_and_text(brand_icon, brand.as_deref(), "span");
+
+            format!(r#"<a class="brand" href="{href}" {label}>{content}</a>"#)
+        } else {
+            String::new()
+        };
+
+        let current = current.or_else(|| {
+            let document = document?;
+            links
+                .iter()
+                .filter_map(|link| Some((link.matches_document(&document)?, link)))
+                .rev()
+                .max_by_key(|(closeness, _)| *closeness)
+                .map(|(_, link)| link.href.clone())
+        });
+
+        let links: String = links
+            .iter()
+            .map(|link| {
+                let current_attribute = if current.as_ref() == Some(&link.href) {
+                    r#"aria-current="page""#
+                } else {
+                    ""
+                };
+
+                format!("<li>{}</li>", link.to_html(current_attribute))
+            })
+            .collect();
+
+        Ok(format!(
+            include_str!("template.html"),
+            id = id,
+            brand = brand,
+            menu_icon = Icon::Menu.svg(),
+            links = links,
+            children = children,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::Header;
+    use crate::{
+        htmplates::{Link, ToHtml},
+        icon::Icon,
+    };
+
+    fn header(document: Option<&str>, current: Option<&str>) -> Header {
+        Header {
+            id: None,
+            brand: Some("Site".to_string()),
+            brand_icon: None,
+            brand_href: None,
+            links: ["/|Home", "/blog/|Blog", "/about|About"]
+                .map(|link| Link::from_str(link).unwrap())
+                .to_vec(),
+            current: current.map(str::to_string),
+            children: None,
+            document: document.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn brand() {
+        let html = header(None, None).to_html().unwrap();
+        assert!(html.contains(r#"<a class="brand" href="/" ><span>Site</span></a>"#));
+
+        let html = Header {
+            brand: None,
+            brand_icon: Some(Icon::Home),
+            ..header(None, None)
+        }
+        .to_html()
+        .unwrap();
+        assert!(html.contains(r#"<a class="brand" href="/" aria-label="Home"><svg "#));
+        assert!(html.contains(r#" class="mauve" "#));
+        assert!(!html.contains("<span>"));
+
+        let html = Header {
+            brand: None,
+            ..header(None, None)
+        }
+        .to_html()
+        .unwrap();
+        assert!(!html.contains("brand"));
+    }
+
+    #[test]
+    fn current_link_from_the_document() {
+        let html = header(Some("site/blog/index.html"), None)
+            .to_html()
+            .unwrap();
+        assert!(html.contains(r#"href="/blog/" aria-current="page""#));
+        assert_eq!(html.matches("aria-current").count(), 1);
+
+        let html = header(Some("site/about.html"), None).to_html().unwrap();
+        assert!(html.contains(r#"href="/about" aria-current="page""#));
+
+        let html = header(Some("site/blog/post.html"), None).to_html().unwrap();
+        assert!(!html.contains("aria-current"));
+    }
+
+    #[test]
+    fn current_overrides_the_document() {
+        let html = header(Some("site/index.html"), Some("/about"))
+            .to_html()
+            .unwrap();
+        assert!(html.contains(r#"href="/about" aria-current="page""#));
+        assert_eq!(html.matches("aria-current").count(), 1);
+    }
+}
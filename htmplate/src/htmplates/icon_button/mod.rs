@@ -18,6 +18,8 @@ pub struct IconButton {
     pub href: Option<String>,
     /// should the link button open in a new tab, defaults to false
     pub new_tab: Option<bool>,
+    /// this should be the ID of a dialog that this button opens
+    pub dialog: Option<String>,
 }
 
 impl ToHtml for IconButton {
@@ -27,8 +29,16 @@ impl ToHtml for IconButton {
             icon,
             href,
             new_tab,
+            dialog,
         } = self;
 
+        if href.is_some() && dialog.is_some() {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "dialog",
+                "a link button cannot open a dialog",
+            ));
+        }
+
         let circle_class = if text.is_none() { "circle" } else { "" };
 
         let icon = icon.map(|icon| icon.svg()).unwrap_or_default();
@@ -51,11 +61,19 @@ impl ToHtml for IconButton {
                 circle_class = circle_class,
             )
         } else {
+            let dialog_attributes = dialog
+                .map(|dialog| {
+                    format!(
+                        r#"type="button" data-dialog="{dialog}" aria-haspopup="dialog" aria-controls="{dialog}""#
+                    )
+                })
+                .unwrap_or_default();
             format!(
                 include_str!("button.template.html"),
                 icon = icon,
                 text = text,
                 circle_class = circle_class,
+                dialog_attributes = dialog_attributes,
             )
         };
 
@@ -0,0 +1,204 @@
+use core::str::FromStr;
+use std::path::{Path, PathBuf};
+
+use htmplate_derive::HtmplateElement;
+
+use crate::htmplates::{HtmplateErrorKind, ToHtml};
+
+use crate as htmplate;
+
+/// When an image is loaded.
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs)]
+pub enum Loading {
+    #[default]
+    Lazy,
+    Eager,
+}
+impl Loading {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lazy => "lazy",
+            Self::Eager => "eager",
+        }
+    }
+}
+impl FromStr for Loading {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lazy" => Ok(Self::Lazy),
+            "eager" => Ok(Self::Eager),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(HtmplateElement)]
+/// an image with its width and height read from the file, to avoid layout shift
+pub struct Img {
+    /// this should be the path to the image, relative to this document or starting with `/` to be relative to `root`
+    pub src: String,
+    /// this should describe the image, required unless the image is decorative
+    pub alt: Option<String>,
+    /// this should be "true" if the image is only decoration and has no `alt`
+    pub decorative: Option<bool>,
+    /// this should be one of [lazy, eager], defaults to lazy
+    pub loading: Option<Loading>,
+    /// this should be the path to the root of the site, relative to this document, defaults to this document's directory
+    #[htmplate(path)]
+    pub root: Option<PathBuf>,
+    /// the path of the document, used to find the image
+    #[htmplate(document)]
+    pub document: Option<PathBuf>,
+}
+
+impl ToHtml for Img {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            src,
+            alt,
+            decorative,
+            loading,
+            root,
+            document,
+        } = self;
+
+        let alt = match (alt, decorative.is_some_and(|decorative| decorative)) {
+            (Some(alt), false) => alt,
+            (None, true) => String::new(),
+            (Some(_), true) => {
+                return Err(HtmplateErrorKind::invalid_attribute(
+                    "alt",
+                    "a decorative image should not have an `alt`",
+                ));
+            }
+            (None, false) => {
+                return Err(HtmplateErrorKind::invalid_attribute(
+                    "alt",
+                    "this should describe the image, or set `decorative` if it is only decoration",
+                ));
+            }
+        };
+
+        if ["http://", "https://", "//"]
+            .iter()
+            .any(|prefix| src.starts_with(prefix))
+        {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "src",
+                "this should be a path to a local image, remote images can't be read for their size",
+            ));
+        }
+
+        let directory = document.as_deref().and_then(Path::parent);
+        let path = match src.strip_prefix('/') {
+            Some(src) => match root.as_deref().or(directory) {
+                Some(root) => root.join(src),
+                None => PathBuf::from(src),
+            },
+            None => match directory {
+                Some(directory) => directory.join(&src),
+                None => PathBuf::from(&src),
+            },
+        };
+        let size =
+            imagesize::size(&path).map_err(|source| HtmplateErrorKind::read_file(&path, source))?;
+
+        Ok(format!(
+            include_str!("template.html"),
+            src = src,
+            alt = alt,
+            width = size.width,
+            height = size.height,
+            loading = loading.unwrap_or_default().as_str(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Img;
+    use crate::{
+        htmplates::{HtmplateErrorKind, ToHtml},
+        test_files::write_files,
+    };
+
+    /// The start of a PNG that is 640 by 360 pixels, enough to read its size.
+    const PNG: &[u8] = &[
+        0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n', 0, 0, 0, 13, b'I', b'H', b'D', b'R', 0,
+        0, 2, 128, 0, 0, 1, 104, 8, 6, 0, 0, 0,
+    ];
+
+    fn img(src: &str, alt: Option<&str>, decorative: Option<bool>) -> Img {
+        Img {
+            src: src.to_string(),
+            alt: alt.map(str::to_string),
+            decorative,
+            loading: None,
+            root: None,
+            document: None,
+        }
+    }
+
+    fn is_invalid(result: Result<String, HtmplateErrorKind>, attribute: &str) -> bool {
+        matches!(
+            result,
+            Err(HtmplateErrorKind::InvalidAttribute { attribute: invalid, .. }) if invalid == attribute
+        )
+    }
+
+    #[test]
+    fn alt_or_decorative() {
+        assert!(is_invalid(img("a.png", None, None).to_html(), "alt"));
+        assert!(is_invalid(img("a.png", None, Some(false)).to_html(), "alt"));
+        assert!(is_invalid(
+            img("a.png", Some("A"), Some(true)).to_html(),
+            "alt"
+        ));
+    }
+
+    #[test]
+    fn remote_src() {
+        for src in [
+            "https://example.com/a.png",
+            "http://example.com/a.png",
+            "//example.com/a.png",
+        ] {
+            assert!(is_invalid(img(src, Some("A"), None).to_html(), "src"));
+        }
+    }
+
+    #[test]
+    fn relative_and_root_relative_src() {
+        let directory = write_files("img", &[("blog/index.html", "")]);
+        fs::create_dir_all(directory.join("img")).unwrap();
+        fs::write(directory.join("img/hero.png"), PNG).unwrap();
+        fs::write(directory.join("blog/hero.png"), PNG).unwrap();
+
+        let html = Img {
+            document: Some(directory.join("blog/index.html")),
+            ..img("hero.png", Some("A hero"), None)
+        }
+        .to_html()
+        .unwrap();
+        assert!(html.contains(r#"src="hero.png""#));
+        assert!(html.contains(r#"alt="A hero""#));
+        assert!(html.contains(r#"width="640""#));
+        assert!(html.contains(r#"height="360""#));
+
+        let html = Img {
+            root: Some(directory.join("blog/..")),
+            document: Some(directory.join("blog/index.html")),
+            ..img("/img/hero.png", None, Some(true))
+        }
+        .to_html()
+        .unwrap();
+        assert!(html.contains(r#"src="/img/hero.png""#));
+        assert!(html.contains(r#"alt="""#));
+        assert!(html.contains(r#"width="640""#));
+    }
+}
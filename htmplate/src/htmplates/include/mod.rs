@@ -0,0 +1,223 @@
+use std::{fs, path::PathBuf};
+
+use htmplate_derive::HtmplateElement;
+use lol_html::html_content::{ContentType, Element};
+
+use crate::{
+    HtmplateElement,
+    attribute_source::ElementAttributes,
+    context::Context,
+    htmplates::{HtmplateError, HtmplateErrorKind, ToHtml},
+    rewrite_htmplates,
+};
+
+use crate as htmplate;
+
+#[derive(HtmplateElement)]
+/// a partial HTML file included into this document, htmplates inside of it are replaced
+pub struct Include {
+    /// this should be the path to the partial, relative to this document
+    #[htmplate(path)]
+    pub src: PathBuf,
+}
+
+impl Include {
+    /// Read the partial and replace its htmplates, in a pass included by `context` if there is one.
+    fn replace(&self, context: Option<&Context>) -> Result<String, HtmplateErrorKind> {
+        let html = fs::read_to_string(&self.src)
+            .map_err(|source| HtmplateErrorKind::read_file(&self.src, source))?;
+
+        let context = match context {
+            Some(context) => context
+                .include(&html, &self.src)
+                .map_err(|chain| HtmplateErrorKind::IncludeCycle { chain })?,
+            None => Context::new(&html, &self.src),
+        };
+
+        rewrite_htmplates(&html, &context).map_err(|source| HtmplateErrorKind::Partial {
+            path: self.src.clone(),
+            source: Box::new(source),
+        })
+    }
+}
+
+impl ToHtml for Include {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        self.replace(None)
+    }
+}
+
+/// Create the replacer for an include, the partial is replaced in a pass of its own so errors
+/// point inside of it.
+pub(crate) fn include_replacer(
+    el: &mut Element,
+    context: &Context,
+) -> Result<(), Box<dyn core::error::Error + Send + Sync + 'static>> {
+    if context.is_suppressed() {
+        return Ok(());
+    }
+
+    let location = context.location(el.source_location().bytes().start);
+    let source = ElementAttributes::new(el, location, context.path().to_path_buf());
+    let include = Include::from_attributes(&source)?;
+    let tag = el.tag_name();
+
+    let html = include
+        .replace(Some(context))
+        .map_err(|kind| HtmplateError {
+            tag: tag.clone(),
+            location: source.location.clone(),
+            kind,
+        })?;
+
+    el.start_tag().remove();
+    el.before(&html, ContentType::Html);
+
+    // Self-closing htmplates don't have an end tag, an explicit one is removed too as long as
+    // there is nothing before it.
+    let children_start = el.source_location().bytes().end;
+    if !el.is_self_closing()
+        && el.can_have_content()
+        && let Some(handlers) = el.end_tag_handlers()
+    {
+        let context = context.clone();
+        context.suppress();
+
+        handlers.push(Box::new(move |end| {
+            context.unsuppress();
+
+            let children = context.source(children_start..end.source_location().bytes().start);
+            if !children.trim().is_empty() {
+                return Err(Box::new(HtmplateError {
+                    tag,
+                    location: source.location,
+                    kind: HtmplateErrorKind::unexpected_children(
+                        "an include should not have children",
+                    ),
+                }));
+            }
+            end.remove();
+
+            Ok(())
+        }));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{error_messages, replace_index, write_files};
+
+    #[test]
+    fn nested_include() {
+        let directory = write_files(
+            "include-nested",
+            &[
+                (
+                    "index.html",
+                    r#"<main><htmplate:include src="partials/outer.html"/></main>"#,
+                ),
+                (
+                    "partials/outer.html",
+                    r#"<section><htmplate:include src="inner.html"/></section>"#,
+                ),
+                ("partials/inner.html", "<p>inner</p>"),
+            ],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.ends_with("<main><section><p>inner</p></section></main>"));
+    }
+
+    #[test]
+    fn explicit_end_tag() {
+        let directory = write_files(
+            "include-end-tag",
+            &[
+                (
+                    "index.html",
+                    r#"<main><htmplate:include src="partial.html"></htmplate:include><p>after</p></main>"#,
+                ),
+                ("partial.html", "<p>partial</p>"),
+            ],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.ends_with("<main><p>partial</p><p>after</p></main>"));
+    }
+
+    #[test]
+    fn children() {
+        let directory = write_files(
+            "include-children",
+            &[
+                (
+                    "index.html",
+                    r#"<htmplate:include src="partial.html"><htmplate:nothing/></htmplate:include>"#,
+                ),
+                ("partial.html", "<p>partial</p>"),
+            ],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("an include should not have children"))
+        );
+    }
+
+    #[test]
+    fn include_cycle() {
+        let directory = write_files(
+            "include-cycle",
+            &[
+                ("index.html", r#"<htmplate:include src="a.html"/>"#),
+                ("a.html", r#"<htmplate:include src="b.html"/>"#),
+                ("b.html", r#"<htmplate:include src="a.html"/>"#),
+            ],
+        );
+        let messages = error_messages(&directory);
+        let cycle = messages
+            .iter()
+            .find(|message| message.starts_with("include cycle"))
+            .unwrap();
+
+        let chain: Vec<&str> = cycle.split(" -> ").collect();
+        assert_eq!(chain.len(), 4);
+        assert!(chain[0].ends_with("/index.html`"));
+        assert!(chain[1].ends_with("/a.html`"));
+        assert!(chain[2].ends_with("/b.html`"));
+        assert!(chain[3].ends_with("/a.html`"));
+    }
+
+    #[test]
+    fn error_inside_partial() {
+        let directory = write_files(
+            "include-error",
+            &[
+                (
+                    "index.html",
+                    "<main>\n  <htmplate:include src=\"partial.html\"/>\n</main>",
+                ),
+                (
+                    "partial.html",
+                    "<section>\n  <p>text</p>\n    <htmplate:alert status=\"loud\"/>\n</section>",
+                ),
+            ],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("index.html:2:3"))
+        );
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("partial.html:3:5"))
+        );
+    }
+}
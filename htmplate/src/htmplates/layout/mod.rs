@@ -0,0 +1,567 @@
+use core::{cell::RefCell, ops::Range};
+use std::{collections::HashMap, fs, path::PathBuf, rc::Rc};
+
+use htmplate_derive::HtmplateElement;
+use lol_html::{
+    Settings, element,
+    errors::RewritingError,
+    html_content::{ContentType, Element},
+    rewrite_str,
+};
+use ts_path::DisplayPath;
+
+use crate::{
+    AttributeSource, HtmplateElement, LocatedAttributes,
+    attribute_source::ElementAttributes,
+    context::Context,
+    htmplates::{HtmplateError, HtmplateErrorKind, ToHtml},
+    rewrite_htmplates,
+};
+
+use crate as htmplate;
+
+type BoxError = Box<dyn core::error::Error + Send + Sync + 'static>;
+
+#[derive(HtmplateElement)]
+/// a page built from a layout file, the layout's blocks are filled by the blocks in the children
+pub struct Layout {
+    /// this should be the path to the layout file, relative to this document
+    #[htmplate(path)]
+    pub src: PathBuf,
+    /// the `htmplate:block` elements that fill the layout's blocks
+    #[htmplate(children)]
+    pub children: Option<String>,
+}
+
+#[derive(HtmplateElement)]
+/// a named block, in a layout it is filled by the page, in a page it fills the layout's block
+pub struct Block {
+    /// this should be the name of the block
+    pub name: String,
+    /// the content of the block, in a layout this is used if the page doesn't fill the block
+    #[htmplate(children)]
+    pub children: Option<String>,
+}
+
+impl ToHtml for Layout {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let file = LayoutFile::read(self.src)?;
+        let children = self.children.unwrap_or_default();
+
+        let mut blocks = HashMap::new();
+        for range in top_level(find_blocks(&children).map_err(HtmplateErrorKind::invalid_children)?)
+        {
+            let name = range.attributes.attribute("name").ok_or_else(|| {
+                HtmplateErrorKind::unexpected_children("each block should have a `name`")
+            })?;
+            file.check_block(&name, &blocks)?;
+            blocks.insert(name, children[range.content].to_string());
+        }
+
+        file.replace(blocks, None)
+    }
+}
+
+impl ToHtml for Block {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        Ok(self.children.unwrap_or_default())
+    }
+}
+
+/// A layout file and the names of its blocks.
+struct LayoutFile {
+    path: PathBuf,
+    html: String,
+    names: Vec<String>,
+}
+impl LayoutFile {
+    fn read(path: PathBuf) -> Result<Self, HtmplateErrorKind> {
+        let html = fs::read_to_string(&path)
+            .map_err(|source| HtmplateErrorKind::read_file(&path, source))?;
+
+        let partial_error = |source| HtmplateErrorKind::Partial {
+            path: path.clone(),
+            source: Box::new(source),
+        };
+
+        // the content of a `<title>` is text, so the blocks in it are found separately
+        let mut ranges = find_blocks(&html).map_err(partial_error)?;
+        for title in find_elements(&html, "title").map_err(partial_error)? {
+            ranges.extend(
+                find_blocks(&html[title.content.clone()])
+                    .map_err(partial_error)?
+                    .into_iter()
+                    .map(|range| ElementRange {
+                        element: range.element.start + title.content.start
+                            ..range.element.end + title.content.start,
+                        ..range
+                    }),
+            );
+        }
+        ranges.sort_by_key(|range| range.element.start);
+
+        let mut names = Vec::new();
+        for range in ranges {
+            if let Some(name) = range.attributes.attribute("name")
+                && !names.contains(&name)
+            {
+                names.push(name);
+            }
+        }
+
+        Ok(Self { path, html, names })
+    }
+
+    /// Check that a page can fill a block.
+    fn check_block(
+        &self,
+        name: &str,
+        blocks: &HashMap<String, String>,
+    ) -> Result<(), HtmplateErrorKind> {
+        if !self.names.iter().any(|block| block == name) {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "name",
+                format!(
+                    "this should be one of the blocks in `{}` [{}]",
+                    self.path.opinionated_display(),
+                    self.names.join(", ")
+                ),
+            ));
+        }
+        if blocks.contains_key(name) {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "name",
+                format!("the block `{name}` is already filled"),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Replace the htmplates in the layout, with its blocks filled, in a pass included by
+    /// `context` if there is one.
+    ///
+    /// The content of a `<title>` is text, so the blocks in it are filled after the pass.
+    fn replace(
+        &self,
+        blocks: HashMap<String, String>,
+        context: Option<&Context>,
+    ) -> Result<String, HtmplateErrorKind> {
+        let context = match context {
+            Some(context) => context
+                .include(&self.html, &self.path)
+                .map_err(|chain| HtmplateErrorKind::IncludeCycle { chain })?,
+            None => Context::new(&self.html, &self.path),
+        }
+        .with_blocks(blocks);
+
+        rewrite_htmplates(&self.html, &context)
+            .and_then(|html| match context.blocks() {
+                Some(blocks) => Ok(fill_title_blocks(&html, blocks)?),
+                None => Ok(html),
+            })
+            .map_err(|source| HtmplateErrorKind::Partial {
+                path: self.path.clone(),
+                source: Box::new(source),
+            })
+    }
+}
+
+/// Create the replacer for a layout, the blocks are replaced in the page's pass and the layout
+/// file in a pass of its own so errors point at the right file.
+pub(crate) fn layout_replacer(el: &mut Element, context: &Context) -> Result<(), BoxError> {
+    if context.is_suppressed() {
+        return Ok(());
+    }
+
+    let location = context.location(el.source_location().bytes().start);
+    let source = ElementAttributes::new(el, location, context.path().to_path_buf());
+    let layout = Layout::from_attributes(&source)?;
+    let tag = el.tag_name();
+
+    let file = LayoutFile::read(layout.src).map_err(|kind| HtmplateError {
+        tag: tag.clone(),
+        location: source.location.clone(),
+        kind,
+    })?;
+
+    if el.is_self_closing() || !el.can_have_content() {
+        let html = file
+            .replace(HashMap::new(), Some(context))
+            .map_err(|kind| HtmplateError {
+                tag,
+                location: source.location,
+                kind,
+            })?;
+        el.start_tag().remove();
+        el.before(&html, ContentType::Html);
+
+        return Ok(());
+    }
+
+    // Like other htmplates with children, the layout is replaced once its end tag is reached.
+    let children_start = el.source_location().bytes().end;
+    let context = context.clone();
+    context.suppress();
+
+    el.remove();
+    if let Some(handlers) = el.end_tag_handlers() {
+        handlers.push(Box::new(move |end| {
+            context.unsuppress();
+
+            let children = children_start..end.source_location().bytes().start;
+            let blocks =
+                fill_blocks(&file, &context, children).map_err(|error| match error
+                    .downcast::<HtmplateErrorKind>()
+                {
+                    Ok(kind) => Box::new(HtmplateError {
+                        tag: tag.clone(),
+                        location: source.location.clone(),
+                        kind: *kind,
+                    }),
+                    Err(error) => error,
+                })?;
+
+            let html = file
+                .replace(blocks, Some(&context))
+                .map_err(|kind| HtmplateError {
+                    tag,
+                    location: source.location,
+                    kind,
+                })?;
+            end.after(&html, ContentType::Html);
+
+            Ok(())
+        }));
+    }
+
+    Ok(())
+}
+
+/// Replace the htmplates in the blocks a page fills, errors that aren't about a single block are
+/// returned as an [`HtmplateErrorKind`] for the layout.
+fn fill_blocks(
+    file: &LayoutFile,
+    context: &Context,
+    children: Range<usize>,
+) -> Result<HashMap<String, String>, BoxError> {
+    let html = context.source(children.clone());
+    let ranges = top_level(
+        find_blocks(html)
+            .map_err(|source| Box::new(HtmplateErrorKind::invalid_children(source)))?,
+    );
+
+    let mut blocks = HashMap::new();
+    let mut position = 0;
+    for range in ranges {
+        check_outside_blocks(&html[position..range.element.start])?;
+        position = range.element.end;
+
+        let location = context.location(children.start + range.element.start);
+        let block = Block::from_attributes(&LocatedAttributes {
+            attributes: range.attributes,
+            location: location.clone(),
+        })?;
+        file.check_block(&block.name, &blocks)
+            .map_err(|kind| HtmplateError {
+                tag: range.tag,
+                location,
+                kind,
+            })?;
+
+        let content = rewrite_htmplates(
+            &html[range.content.clone()],
+            &context.nested(children.start + range.content.start),
+        )?;
+        blocks.insert(block.name, content);
+    }
+    check_outside_blocks(&html[position..])?;
+
+    Ok(blocks)
+}
+
+fn check_outside_blocks(html: &str) -> Result<(), BoxError> {
+    if html.trim().is_empty() {
+        Ok(())
+    } else {
+        Err(Box::new(HtmplateErrorKind::unexpected_children(
+            "the children of a layout should only be blocks",
+        )))
+    }
+}
+
+/// Fill the blocks in the `<title>` elements of some HTML.
+fn fill_title_blocks(
+    html: &str,
+    blocks: &HashMap<String, String>,
+) -> Result<String, RewritingError> {
+    let mut filled = String::with_capacity(html.len());
+    let mut position = 0;
+    for title in find_elements(html, "title")? {
+        filled.push_str(&html[position..title.content.start]);
+        filled.push_str(&fill_text_blocks(&html[title.content.clone()], blocks)?);
+        position = title.content.end;
+    }
+    filled.push_str(&html[position..]);
+
+    Ok(filled)
+}
+
+/// Fill the blocks in some text, blocks the page doesn't fill are replaced with their children.
+fn fill_text_blocks(
+    text: &str,
+    blocks: &HashMap<String, String>,
+) -> Result<String, RewritingError> {
+    let mut filled = String::with_capacity(text.len());
+    let mut position = 0;
+    for block in top_level(find_blocks(text)?) {
+        filled.push_str(&text[position..block.element.start]);
+        match block
+            .attributes
+            .attribute("name")
+            .and_then(|name| blocks.get(&name))
+        {
+            Some(content) => filled.push_str(content),
+            None => filled.push_str(&fill_text_blocks(&text[block.content], blocks)?),
+        }
+        position = block.element.end;
+    }
+    filled.push_str(&text[position..]);
+
+    Ok(filled)
+}
+
+/// An element and where it is in some HTML.
+struct ElementRange {
+    tag: String,
+    attributes: Vec<(String, String)>,
+    element: Range<usize>,
+    content: Range<usize>,
+}
+
+/// Find the blocks in some HTML, in the order they start.
+fn find_blocks(html: &str) -> Result<Vec<ElementRange>, RewritingError> {
+    find_elements(html, Block::tag())
+}
+
+/// Find the elements matching a selector in some HTML, in the order they start.
+fn find_elements(html: &str, selector: &str) -> Result<Vec<ElementRange>, RewritingError> {
+    let elements: Rc<RefCell<Vec<ElementRange>>> = Rc::default();
+
+    rewrite_str(
+        html,
+        Settings {
+            element_content_handlers: vec![element!(selector, |el| {
+                record_element(el, &elements);
+                Ok(())
+            })],
+            ..Settings::new()
+        },
+    )?;
+
+    let mut elements = elements.take();
+    elements.sort_by_key(|element| element.element.start);
+
+    Ok(elements)
+}
+
+/// Remove the blocks nested inside of another block, they are part of its content.
+fn top_level(blocks: Vec<ElementRange>) -> Vec<ElementRange> {
+    let mut end = 0;
+    blocks
+        .into_iter()
+        .filter(|block| {
+            if block.element.start < end {
+                return false;
+            }
+            end = block.element.end;
+            true
+        })
+        .collect()
+}
+
+/// Record an element once its end tag is reached.
+fn record_element(el: &mut Element, elements: &Rc<RefCell<Vec<ElementRange>>>) {
+    let attributes = el
+        .attributes()
+        .iter()
+        .map(|attribute| (attribute.name(), attribute.value()))
+        .collect();
+    let tag = el.tag_name();
+    let start = el.source_location().bytes();
+
+    if el.is_self_closing() || !el.can_have_content() {
+        elements.borrow_mut().push(ElementRange {
+            tag,
+            attributes,
+            content: start.end..start.end,
+            element: start,
+        });
+    } else if let Some(handlers) = el.end_tag_handlers() {
+        let elements = Rc::clone(elements);
+        handlers.push(Box::new(move |end| {
+            let end = end.source_location().bytes();
+            elements.borrow_mut().push(ElementRange {
+                tag,
+                attributes,
+                element: start.start..end.end,
+                content: start.end..end.start,
+            });
+            Ok(())
+        }));
+    }
+}
+
+/// Create the replacer for a block, in a layout the block is replaced with the page's content for
+/// it, or its children if the page doesn't fill it.
+pub(crate) fn block_replacer(el: &mut Element, context: &Context) -> Result<(), BoxError> {
+    if context.is_suppressed() {
+        return Ok(());
+    }
+
+    let location = context.location(el.source_location().bytes().start);
+    let source = ElementAttributes::new(el, location, context.path().to_path_buf());
+    let block = Block::from_attributes(&source)?;
+
+    let Some(blocks) = context.blocks() else {
+        return Err(Box::new(HtmplateError {
+            tag: el.tag_name(),
+            location: source.location,
+            kind: HtmplateErrorKind::invalid_attribute(
+                "name",
+                "blocks should be in a layout file or the children of a layout",
+            ),
+        }));
+    };
+
+    // Self-closing blocks don't have an end tag, so only the start tag is removed.
+    if el.is_self_closing() || !el.can_have_content() {
+        el.start_tag().remove();
+        if let Some(content) = blocks.get(&block.name) {
+            el.before(content, ContentType::Html);
+        }
+
+        return Ok(());
+    }
+
+    match blocks.get(&block.name) {
+        Some(content) => {
+            el.replace(content, ContentType::Html);
+
+            // The children are being replaced, so leave the htmplates in them alone.
+            if let Some(handlers) = el.end_tag_handlers() {
+                let context = context.clone();
+                context.suppress();
+                handlers.push(Box::new(move |_| {
+                    context.unsuppress();
+                    Ok(())
+                }));
+            }
+        }
+        None => el.remove_and_keep_content(),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{error_messages, replace_index, write_files};
+
+    const LAYOUT: &str = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title><htmplate:block name="title">Untitled</htmplate:block> | Site</title>
+    <htmplate:block name="head"/>
+  </head>
+  <body>
+    <main><htmplate:block name="main"><p>Nothing here</p></htmplate:block></main>
+  </body>
+</html>"#;
+
+    #[test]
+    fn title_head_and_main_blocks() {
+        let directory = write_files(
+            "layout-blocks",
+            &[
+                ("layout.html", LAYOUT),
+                (
+                    "index.html",
+                    r#"<htmplate:layout src="layout.html">
+  <htmplate:block name="title">Home</htmplate:block>
+  <htmplate:block name="head"><meta name="robots" content="none"></htmplate:block>
+  <htmplate:block name="main"><htmplate:hr text="or"/></htmplate:block>
+</htmplate:layout>"#,
+                ),
+            ],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains("<title>Home | Site</title>"));
+        assert!(html.contains(r#"<meta name="robots" content="none">"#));
+        assert!(html.contains(r#"<div class="hr-text">or</div>"#));
+        assert!(!html.contains("htmplate:"));
+        assert!(!html.contains("Nothing here"));
+    }
+
+    #[test]
+    fn blocks_default_to_their_children() {
+        let directory = write_files(
+            "layout-defaults",
+            &[
+                ("layout.html", LAYOUT),
+                ("index.html", r#"<htmplate:layout src="layout.html"/>"#),
+            ],
+        );
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains("<title>Untitled | Site</title>"));
+        assert!(html.contains("<main><p>Nothing here</p></main>"));
+    }
+
+    #[test]
+    fn unknown_block() {
+        let directory = write_files(
+            "layout-unknown",
+            &[
+                ("layout.html", LAYOUT),
+                (
+                    "index.html",
+                    "<htmplate:layout src=\"layout.html\">\n  <htmplate:block name=\"aside\">text</htmplate:block>\n</htmplate:layout>",
+                ),
+            ],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(messages.iter().any(|message| {
+            message.starts_with("could not template a htmplate:block at")
+                && message.contains("index.html:2:3")
+        }));
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("[title, head, main]"))
+        );
+    }
+
+    #[test]
+    fn children_outside_of_blocks() {
+        let directory = write_files(
+            "layout-stray",
+            &[
+                ("layout.html", LAYOUT),
+                (
+                    "index.html",
+                    r#"<htmplate:layout src="layout.html"><p>stray</p><htmplate:block name="main"/></htmplate:layout>"#,
+                ),
+            ],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("the children of a layout should only be blocks"))
+        );
+    }
+}
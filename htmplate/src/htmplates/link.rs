@@ -0,0 +1,191 @@
+use core::str::FromStr;
+use std::path::{Component, Path, PathBuf};
+
+use crate::icon::Icon;
+
+/// A link with an optional icon, either `href|text` or `href|text|icon`.
+///
+/// `|` can't appear in a URL without being percent-encoded, so hrefs with query strings and commas
+/// are kept whole.
+#[derive(Debug, Clone)]
+pub struct Link {
+    /// Where the link goes.
+    pub href: String,
+    /// The text of the link.
+    pub text: String,
+    /// The icon shown before the text.
+    pub icon: Option<Icon>,
+}
+impl Link {
+    /// Render the link as a ghost button.
+    pub(crate) fn to_html(&self, attributes: &str) -> String {
+        format!(
+            r#"<a class="button ghost" href="{href}" {attributes}>{icon}{text}</a>"#,
+            href = self.href,
+            icon = self
+                .icon
+                .as_ref()
+                .map(|icon| icon.svg())
+                .unwrap_or_default(),
+            text = self.text,
+        )
+    }
+
+    /// How closely the link points at a document, if it does at all.
+    ///
+    /// Relative links are resolved against the document and match it exactly. Root-relative links
+    /// can only match the end of the document's path, so longer ones are closer matches.
+    pub(crate) fn matches_document(&self, document: &Path) -> Option<usize> {
+        let href = self.href.split(['?', '#']).next().unwrap_or_default();
+        if href.contains(':') || href.starts_with("//") {
+            return None;
+        }
+
+        let document = normalise(document);
+        let link = match href.strip_prefix('/') {
+            Some(href) => PathBuf::from(href),
+            None if href.is_empty() => document.clone(),
+            None => normalise(&document.parent().unwrap_or(Path::new("")).join(href)),
+        };
+        // A link to a directory is a link to its index page, and links may leave out the extension.
+        let link = if href.ends_with('/') {
+            link.join("index.html")
+        } else {
+            link
+        };
+        let document = if link.extension().is_none() {
+            document.with_extension("")
+        } else {
+            document
+        };
+
+        if href.starts_with('/') {
+            document.ends_with(&link).then(|| link.components().count())
+        } else {
+            (document == link).then_some(usize::MAX)
+        }
+    }
+}
+impl FromStr for Link {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('|');
+        let href = parts.next().ok_or(())?.trim();
+        let text = parts.next().ok_or(())?.trim();
+        let icon = parts
+            .next()
+            .map(|icon| Icon::from_str(icon.trim()))
+            .transpose()?;
+        if parts.next().is_some() {
+            return Err(());
+        }
+
+        if href.is_empty() || text.is_empty() {
+            return Err(());
+        }
+
+        Ok(Self {
+            href: href.to_string(),
+            text: text.to_string(),
+            icon,
+        })
+    }
+}
+
+/// Resolve the `.` and `..` components of a path without touching the file system.
+pub(crate) fn normalise(path: &Path) -> PathBuf {
+    let mut normalised = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalised.pop() {
+                    normalised.push("..");
+                }
+            }
+            component => normalised.push(component),
+        }
+    }
+    normalised
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+    use std::path::Path;
+
+    use super::Link;
+
+    #[test]
+    fn href_and_text() {
+        let link = Link::from_str(" /about | About us ").unwrap();
+
+        assert_eq!(link.href, "/about");
+        assert_eq!(link.text, "About us");
+        assert!(link.icon.is_none());
+    }
+
+    #[test]
+    fn icon() {
+        let link = Link::from_str("/|Home|home").unwrap();
+
+        assert!(link.icon.is_some());
+    }
+
+    #[test]
+    fn href_with_query_string_and_commas() {
+        let link = Link::from_str("/search?q=a=b&tags=x,y|Search").unwrap();
+
+        assert_eq!(link.href, "/search?q=a=b&tags=x,y");
+        assert_eq!(link.text, "Search");
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Link::from_str("/about").is_err());
+        assert!(Link::from_str("|About").is_err());
+        assert!(Link::from_str("/about|").is_err());
+        assert!(Link::from_str("/|Home|not-an-icon").is_err());
+        assert!(Link::from_str("/|Home|home|extra").is_err());
+    }
+
+    fn matches(href: &str, document: &str) -> Option<usize> {
+        Link::from_str(&format!("{href}|Link"))
+            .unwrap()
+            .matches_document(Path::new(document))
+    }
+
+    #[test]
+    fn relative_links_match_exactly() {
+        assert_eq!(matches("about.html", "site/about.html"), Some(usize::MAX));
+        assert_eq!(
+            matches("../blog/about", "site/blog/./about.html"),
+            Some(usize::MAX)
+        );
+        assert_eq!(matches("./", "site/index.html"), Some(usize::MAX));
+        assert_eq!(matches("../", "site/blog/index.html"), None);
+        assert_eq!(
+            matches("about.html#team", "site/blog/about.html"),
+            Some(usize::MAX)
+        );
+        assert_eq!(matches("contact.html", "site/about.html"), None);
+    }
+
+    #[test]
+    fn root_relative_links_match_the_end() {
+        assert_eq!(matches("/", "site/index.html"), Some(1));
+        assert_eq!(matches("/blog/", "site/blog/index.html"), Some(2));
+        assert_eq!(matches("/blog/?page=2", "site/blog/index.html"), Some(2));
+        assert_eq!(matches("/about", "site/about.html"), Some(1));
+        assert_eq!(matches("/", "site/about.html"), None);
+        assert_eq!(matches("/blog/", "site/blog/post.html"), None);
+    }
+
+    #[test]
+    fn remote_links_never_match() {
+        assert_eq!(matches("https://example.com/", "site/index.html"), None);
+        assert_eq!(matches("//example.com/index.html", "site/index.html"), None);
+        assert_eq!(matches("mailto:a@example.com", "site/index.html"), None);
+    }
+}
@@ -0,0 +1,147 @@
+use htmplate_derive::HtmplateElement;
+
+use crate::htmplates::{HtmplateErrorKind, ToHtml};
+
+use crate as htmplate;
+
+#[derive(HtmplateElement)]
+/// a spinner shown while something is loading
+pub struct Spinner {
+    /// this should be the text read by screen readers, defaults to "Loading"
+    pub label: Option<String>,
+}
+
+#[derive(HtmplateElement)]
+/// a progress bar, without a value it shows that something is loading
+pub struct Progress {
+    /// this should be how much of the task is done, between 0 and `max`, leave it unset if this isn't known
+    pub value: Option<f64>,
+    /// this should be the value when the task is done, defaults to 1
+    pub max: Option<f64>,
+    /// this should be the text read by screen readers, defaults to "Loading"
+    pub label: Option<String>,
+}
+
+#[derive(HtmplateElement)]
+/// placeholder lines shown in place of content that is loading
+pub struct Skeleton {
+    /// this should be the number of lines, defaults to 3
+    pub lines: Option<u8>,
+    /// this should be the text read by screen readers, defaults to "Loading"
+    pub label: Option<String>,
+}
+
+impl ToHtml for Spinner {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        Ok(format!(
+            include_str!("spinner.template.html"),
+            label = self.label.as_deref().unwrap_or("Loading"),
+        ))
+    }
+}
+
+impl ToHtml for Progress {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self { value, max, label } = self;
+
+        let max = max.unwrap_or(1.0);
+        if max <= 0.0 {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "max",
+                "this should be greater than 0",
+            ));
+        }
+        if let Some(value) = value
+            && !(0.0..=max).contains(&value)
+        {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "value",
+                format!("this should be between 0 and {max}"),
+            ));
+        }
+
+        Ok(format!(
+            include_str!("progress.template.html"),
+            max = max,
+            value = value
+                .map(|value| format!(r#"value="{value}""#))
+                .unwrap_or_default(),
+            label = label.as_deref().unwrap_or("Loading"),
+            busy = value.is_none_or(|value| value < max),
+        ))
+    }
+}
+
+impl ToHtml for Skeleton {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self { lines, label } = self;
+
+        let lines = lines.unwrap_or(3);
+        if lines == 0 {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "lines",
+                "this should be at least 1",
+            ));
+        }
+
+        Ok(format!(
+            include_str!("skeleton.template.html"),
+            lines = r#"<span class="skeleton-line"></span>"#.repeat(usize::from(lines)),
+            label = label.as_deref().unwrap_or("Loading"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{error_messages, replace_index, write_files};
+
+    #[test]
+    fn skeleton_lines() {
+        let directory = write_files(
+            "skeleton",
+            &[(
+                "index.html",
+                r#"<htmplate:skeleton/><htmplate:skeleton lines="5" label="Loading posts"/>"#,
+            )],
+        );
+        let html = replace_index(&directory).unwrap();
+        let (first, second) = html.split_once(r#"aria-label="Loading posts""#).unwrap();
+
+        assert_eq!(first.matches("skeleton-line").count(), 3);
+        assert_eq!(second.matches("skeleton-line").count(), 5);
+    }
+
+    #[test]
+    fn skeleton_without_lines() {
+        let directory = write_files(
+            "skeleton-empty",
+            &[("index.html", r#"<htmplate:skeleton lines="0"/>"#)],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("this should be at least 1"))
+        );
+    }
+
+    #[test]
+    fn progress() {
+        let directory = write_files(
+            "progress",
+            &[(
+                "index.html",
+                r#"<htmplate:progress value="3" max="4"/><htmplate:progress value="5" max="4"/>"#,
+            )],
+        );
+        let messages = error_messages(&directory);
+
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("this should be between 0 and 4"))
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::{collections::HashSet, fs, path::PathBuf};
+
+use htmplate_derive::HtmplateElement;
+use pulldown_cmark::{
+    BlockQuoteKind, CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd, html,
+};
+
+use crate::htmplates::{
+    Alert, AlertStyle, HtmplateErrorKind, ToHtml,
+    code::{code_block, find_syntax, plain_text_syntax},
+};
+
+use crate as htmplate;
+
+#[derive(HtmplateElement)]
+/// a Markdown file rendered to HTML when templating
+pub struct Markdown {
+    /// this should be the path to the Markdown file, relative to this document
+    #[htmplate(path)]
+    pub src: PathBuf,
+}
+
+impl ToHtml for Markdown {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let markdown = fs::read_to_string(&self.src)
+            .map_err(|source| HtmplateErrorKind::read_file(&self.src, source))?;
+
+        let options = Options::ENABLE_TABLES
+            | Options::ENABLE_FOOTNOTES
+            | Options::ENABLE_STRIKETHROUGH
+            | Options::ENABLE_TASKLISTS
+            | Options::ENABLE_HEADING_ATTRIBUTES
+            | Options::ENABLE_GFM;
+        let events = map_events(Parser::new_ext(&markdown, options))?;
+
+        let mut content = String::new();
+        html::push_html(&mut content, events.into_iter());
+        let content = content.replace("<table>", r#"<table class="table">"#);
+
+        Ok(format!(include_str!("template.html"), content = content))
+    }
+}
+
+/// Map the Markdown onto the built-in markup: code blocks are highlighted, admonitions become
+/// alerts, tables are styled and headings get a unique ID to link to.
+fn map_events<'a>(
+    parser: impl Iterator<Item = Event<'a>>,
+) -> Result<Vec<Event<'a>>, HtmplateErrorKind> {
+    let mut events = Vec::new();
+    let mut code: Option<(Option<String>, String)> = None;
+    let mut heading: Option<usize> = None;
+    let mut slugs = HashSet::new();
+
+    for event in parser {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let lang = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().map(|lang| lang.to_string())
+                    }
+                    CodeBlockKind::Indented => None,
+                };
+                code = Some((lang, String::new()));
+            }
+            Event::Text(text) if code.is_some() => {
+                if let Some((_, code)) = &mut code {
+                    code.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                let (lang, code) = code.take().unwrap_or_default();
+                let syntax = lang
+                    .as_deref()
+                    .and_then(find_syntax)
+                    .unwrap_or_else(plain_text_syntax);
+                let html = code_block(&code, lang.as_deref(), syntax, false, true)?;
+                events.push(Event::Html(CowStr::from(html)));
+            }
+
+            Event::Start(Tag::BlockQuote(Some(kind))) => {
+                let (open, _) = Alert::around_text(&alert_style(kind));
+                events.push(Event::Html(CowStr::from(open)));
+            }
+            Event::End(TagEnd::BlockQuote(Some(kind))) => {
+                let (_, close) = Alert::around_text(&alert_style(kind));
+                events.push(Event::Html(CowStr::from(close)));
+            }
+
+            Event::Start(Tag::Table(alignments)) => {
+                events.push(Event::Html(CowStr::Borrowed(
+                    r#"<div class="table-container">"#,
+                )));
+                events.push(Event::Start(Tag::Table(alignments)));
+            }
+            Event::End(TagEnd::Table) => {
+                events.push(Event::End(TagEnd::Table));
+                events.push(Event::Html(CowStr::Borrowed("</div>")));
+            }
+
+            Event::Start(Tag::Heading {
+                id: Some(ref id), ..
+            }) => {
+                slugs.insert(id.to_string());
+                events.push(event);
+            }
+            Event::Start(Tag::Heading { id: None, .. }) => {
+                heading = Some(events.len());
+                events.push(event);
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(start) = heading.take() {
+                    let text: String = events[start + 1..]
+                        .iter()
+                        .filter_map(|event| match event {
+                            Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                            _ => None,
+                        })
+                        .collect();
+                    if let Event::Start(Tag::Heading { id, .. }) = &mut events[start] {
+                        *id = Some(CowStr::from(unique_slug(&text, &mut slugs)));
+                    }
+                }
+                events.push(event);
+            }
+
+            event => events.push(event),
+        }
+    }
+
+    Ok(events)
+}
+
+fn alert_style(kind: BlockQuoteKind) -> AlertStyle {
+    match kind {
+        BlockQuoteKind::Note => AlertStyle::Info,
+        BlockQuoteKind::Tip => AlertStyle::Success,
+        BlockQuoteKind::Important => AlertStyle::Basic,
+        BlockQuoteKind::Warning => AlertStyle::Warning,
+        BlockQuoteKind::Caution => AlertStyle::Error,
+    }
+}
+
+/// Create an ID for a heading from its text that isn't already used, numbering repeated IDs like
+/// GitHub does.
+fn unique_slug(text: &str, used: &mut HashSet<String>) -> String {
+    let slug = slug(text);
+    let mut unique = slug.clone();
+    let mut number = 0;
+    while !used.insert(unique.clone()) {
+        number += 1;
+        unique = format!("{slug}-{number}");
+    }
+
+    unique
+}
+
+/// Create an ID for a heading from its text.
+fn slug(text: &str) -> String {
+    text.to_lowercase()
+        .split(|char: char| !char.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use pulldown_cmark::{Options, Parser, html};
+
+    use super::map_events;
+    use crate::htmplates::{Alert, AlertStyle};
+
+    fn render(markdown: &str) -> String {
+        let parser = Parser::new_ext(
+            markdown,
+            Options::ENABLE_HEADING_ATTRIBUTES | Options::ENABLE_GFM,
+        );
+        let mut html = String::new();
+        html::push_html(&mut html, map_events(parser).unwrap().into_iter());
+        html
+    }
+
+    #[test]
+    fn repeated_headings_get_unique_ids() {
+        let html = render("# Intro\n## Intro\n# Usage {#intro-2}\n### Intro!\n# Intro 1\n");
+
+        assert_eq!(
+            html,
+            concat!(
+                "<h1 id=\"intro\">Intro</h1>\n",
+                "<h2 id=\"intro-1\">Intro</h2>\n",
+                "<h1 id=\"intro-2\">Usage</h1>\n",
+                "<h3 id=\"intro-3\">Intro!</h3>\n",
+                "<h1 id=\"intro-1-1\">Intro 1</h1>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn admonitions_are_alerts() {
+        let html = render("> [!WARNING]\n> Careful\n");
+        let (open, close) = Alert::around_text(&AlertStyle::Warning);
+
+        assert!(open.starts_with(r#"<aside class="alert warning">"#));
+        assert_eq!(html, format!("{open}\n<p>Careful</p>\n{close}"));
+    }
+}
@@ -1,28 +1,163 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use htmplate_derive::HtmplateElement;
 
-use crate::htmplates::ToHtml;
+use crate::htmplates::{HtmplateErrorKind, ToHtml};
 
 use crate as htmplate;
 
+const PALETTE: &str = include_str!("../../assets/styles/colour.css");
+const FONTS: [&str; 3] = ["Quicksand.ttf", "Nunito.ttf", "FiraCode.ttf"];
+
 #[derive(HtmplateElement)]
 /// common document metadata
 pub struct Metadata {
     /// this should be the path to the root of the website where the library is located
     root: PathBuf,
+    /// this should be the author of the document, defaults to "Trent Shailer"
+    author: Option<String>,
+    /// this should be a short description of the document
+    description: Option<String>,
+    /// this should be a comma separated list of keywords for the document
+    #[htmplate(separator = ",")]
+    keywords: Vec<String>,
+    /// this should be the canonical URL of the document
+    canonical: Option<String>,
+    /// this should be the title shown when the document is shared
+    title: Option<String>,
+    /// this should be the URL of the image shown when the document is shared
+    image: Option<String>,
+    /// this should be the Open Graph type of the document, defaults to "website"
+    og_type: Option<String>,
+    /// this should be the Twitter handle of the site, including the `@`
+    twitter_site: Option<String>,
+    /// this should be a colour from the palette used as the theme colour, defaults to "base"
+    theme_color: Option<String>,
+    /// this should be "false" to not preload the fonts, defaults to true
+    preload_fonts: Option<bool>,
 }
 impl ToHtml for Metadata {
-    fn to_html(self) -> Result<String, super::HtmplateErrorKind> {
-        let Self { root } = self;
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            root,
+            author,
+            description,
+            keywords,
+            canonical,
+            title,
+            image,
+            og_type,
+            twitter_site,
+            theme_color,
+            preload_fonts,
+        } = self;
 
         let favicon_path = root.join("lib").join("favicon.ico");
         let css_path = root.join("lib").join("style.min.css");
 
+        let theme_color = theme_color.unwrap_or_else(|| "base".to_string());
+        let Some((light, dark)) = palette_colour(&theme_color) else {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "theme-color",
+                "this should be the name of a colour in the palette",
+            ));
+        };
+
+        let mut tags = vec![
+            format!(
+                r#"<meta name="theme-color" content="{light}" media="(prefers-color-scheme: light)">"#
+            ),
+            format!(
+                r#"<meta name="theme-color" content="{dark}" media="(prefers-color-scheme: dark)">"#
+            ),
+        ];
+
+        if let Some(description) = &description {
+            tags.push(format!(
+                r#"<meta name="description" content="{description}">"#
+            ));
+        }
+        if !keywords.is_empty() {
+            tags.push(format!(
+                r#"<meta name="keywords" content="{}">"#,
+                keywords.join(", ")
+            ));
+        }
+        if let Some(canonical) = &canonical {
+            tags.push(format!(r#"<link rel="canonical" href="{canonical}">"#));
+        }
+
+        // Open Graph and Twitter cards, only if there is something to share.
+        if title.is_some() || description.is_some() || image.is_some() || canonical.is_some() {
+            let og_type = og_type.as_deref().unwrap_or("website");
+            tags.push(format!(r#"<meta property="og:type" content="{og_type}">"#));
+
+            let card = if image.is_some() {
+                "summary_large_image"
+            } else {
+                "summary"
+            };
+            tags.push(format!(r#"<meta name="twitter:card" content="{card}">"#));
+
+            if let Some(twitter_site) = &twitter_site {
+                tags.push(format!(
+                    r#"<meta name="twitter:site" content="{twitter_site}">"#
+                ));
+            }
+            if let Some(title) = &title {
+                tags.push(format!(r#"<meta property="og:title" content="{title}">"#));
+                tags.push(format!(r#"<meta name="twitter:title" content="{title}">"#));
+            }
+            if let Some(description) = &description {
+                tags.push(format!(
+                    r#"<meta property="og:description" content="{description}">"#
+                ));
+                tags.push(format!(
+                    r#"<meta name="twitter:description" content="{description}">"#
+                ));
+            }
+            if let Some(image) = &image {
+                tags.push(format!(r#"<meta property="og:image" content="{image}">"#));
+                tags.push(format!(r#"<meta name="twitter:image" content="{image}">"#));
+            }
+            if let Some(canonical) = &canonical {
+                tags.push(format!(r#"<meta property="og:url" content="{canonical}">"#));
+            }
+        }
+
+        if preload_fonts.is_none_or(|preload_fonts| preload_fonts) {
+            let fonts_path = root.join("lib").join("fonts");
+            tags.extend(FONTS.iter().map(|font| {
+                format!(
+                    r#"<link rel="preload" href="{}" as="font" type="font/ttf" crossorigin>"#,
+                    web_path(&fonts_path.join(font))
+                )
+            }));
+        }
+
         Ok(format!(
             include_str!("template.html"),
-            favicon = favicon_path.to_string_lossy().replace("\\", "/"),
-            css = css_path.to_string_lossy().replace("\\", "/")
+            author = author.as_deref().unwrap_or("Trent Shailer"),
+            favicon = web_path(&favicon_path),
+            css = web_path(&css_path),
+            tags = tags.join("\n"),
         ))
     }
 }
+
+fn web_path(path: &Path) -> String {
+    path.to_string_lossy().replace("\\", "/")
+}
+
+/// Get the light and dark value of a colour in the palette.
+fn palette_colour(name: &str) -> Option<(&'static str, &'static str)> {
+    let (light, dark) = PALETTE.split_once("prefers-color-scheme: dark")?;
+
+    let find = |section: &'static str| {
+        let start = section.find(&format!("--{name}:"))? + name.len() + 3;
+        let end = section[start..].find(';')? + start;
+        Some(section[start..end].trim())
+    };
+
+    Some((find(light)?, find(dark)?))
+}
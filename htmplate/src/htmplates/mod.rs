@@ -2,28 +2,67 @@
 //!
 
 mod alert;
+mod breadcrumbs;
+mod card;
+mod code;
+mod dialog;
 mod footer;
 mod form;
+mod header;
 mod hr;
 mod icon;
 mod icon_button;
+mod img;
+mod include;
+mod layout;
+mod link;
+mod loading;
+mod markdown;
 mod metadata;
+mod table;
+mod tabs;
+mod time;
 mod title;
+mod toasts;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use lol_html::html_content::ContentType;
+use ts_path::DisplayPath;
 
-pub use alert::Alert;
+pub use alert::{Alert, AlertStyle};
+pub use breadcrumbs::Breadcrumbs;
+pub use card::Card;
+pub use code::Code;
+pub use dialog::Dialog;
 pub use footer::Footer;
-pub use form::{FormAlert, FormCheckInput, FormSubmit, FormTextInput};
+pub use form::{
+    ButtonVariant, Form, FormAlert, FormCheckInput, FormId, FormRadioGroup, FormSelect, FormSubmit,
+    FormTextInput, FormTextarea, InputType, SelectOption,
+};
+pub use header::Header;
 pub use hr::Hr;
 pub use icon::Icon;
 pub use icon_button::IconButton;
+pub use img::{Img, Loading};
+pub use include::Include;
+pub(crate) use include::include_replacer;
+pub use layout::{Block, Layout};
+pub(crate) use layout::{block_replacer, layout_replacer};
+pub use link::Link;
+pub use loading::{Progress, Skeleton, Spinner};
+pub use markdown::Markdown;
 pub use metadata::Metadata;
+pub use table::{Column, ColumnAlign, ColumnType, Table};
+pub use tabs::{Tab, Tabs};
+pub use time::{Time, TimeFormat};
 pub use title::Title;
+pub use toasts::Toasts;
 
-use crate::{HtmplateElement, Location};
+use crate::{
+    AttributeSource, HtmplateElement, Location, attribute_source::ElementAttributes,
+    collapse_whitespace, context::Context, rewrite_htmplates,
+};
 
 /// Trait for turning an htmplate into it's HTML.
 pub trait ToHtml {
@@ -31,53 +70,132 @@ pub trait ToHtml {
     fn to_html(self) -> Result<String, HtmplateErrorKind>;
 }
 
+/// Render an htmplate to HTML with its whitespace collapsed, like replacing it in a document.
+///
+/// Unlike in a document, htmplates in the children aren't replaced and there is no element to copy
+/// attributes like `id` and `class` from.
+pub fn render<T: ToHtml>(htmplate: T) -> Result<String, HtmplateErrorKind> {
+    htmplate.to_html().map(|html| collapse_whitespace(&html))
+}
+
 /// Create a standard replacer for an htmplate.
-pub fn replacer<T: HtmplateElement + ToHtml>(
+pub(crate) fn replacer<T: HtmplateElement + ToHtml>(
     el: &mut lol_html::html_content::Element,
-    html: &str,
-    file_path: &Path,
+    context: &Context,
 ) -> Result<(), Box<dyn core::error::Error + Send + Sync + 'static>> {
-    let htmplate = T::from_element(el, html, file_path)?;
+    scoped_replacer::<T>(el, context, |_, context| context)
+}
 
-    match htmplate.to_html() {
-        Ok(html) => {
-            let html = inject_attributes_from_original_element(el, html);
+/// Create a replacer for an htmplate that changes the context its children are replaced in.
+pub(crate) fn scoped_replacer<T: HtmplateElement + ToHtml>(
+    el: &mut lol_html::html_content::Element,
+    context: &Context,
+    scope: fn(&ElementAttributes, Context) -> Context,
+) -> Result<(), Box<dyn core::error::Error + Send + Sync + 'static>> {
+    if context.is_suppressed() {
+        return Ok(());
+    }
+
+    let location = context.location(el.source_location().bytes().start);
+    let mut source = ElementAttributes::new(el, location, context.path().to_path_buf());
+    let tag = el.tag_name();
 
-            el.start_tag().remove();
-            el.before(&html, ContentType::Html);
+    // Htmplates inside of a form inherit its ID.
+    if let Some(form) = context.form()
+        && T::attributes()
+            .iter()
+            .any(|attribute| attribute.name == "form")
+    {
+        match source.attribute("form") {
+            Some(value) if value != form => {
+                return Err(Box::new(HtmplateError {
+                    tag,
+                    location: source.location,
+                    kind: HtmplateErrorKind::invalid_attribute(
+                        "form",
+                        format!("this should match the enclosing form `{form}`"),
+                    ),
+                }));
+            }
+            Some(_) => {}
+            None => source
+                .attributes
+                .push(("form".to_string(), form.to_string())),
         }
-        Err(kind) => {
-            let location = Location::from_byte_index(
-                el.source_location().bytes().start,
-                html.as_bytes(),
-                file_path,
-            );
-            return Err(Box::new(HtmplateError {
-                tag: el.tag_name(),
-                location,
-                kind,
+    }
+
+    // Htmplates made from their children are replaced once their end tag is reached, the children
+    // are templated in a nested pass instead of this one.
+    if T::accepts_children() && !el.is_self_closing() && el.can_have_content() {
+        let children_start = el.source_location().bytes().end;
+        let context = context.clone();
+        context.suppress();
+
+        el.remove();
+        if let Some(handlers) = el.end_tag_handlers() {
+            handlers.push(Box::new(move |end| {
+                context.unsuppress();
+
+                let children_end = end.source_location().bytes().start;
+                let children = if T::raw_children() {
+                    context.source(children_start..children_end).to_string()
+                } else {
+                    rewrite_htmplates(
+                        context.source(children_start..children_end),
+                        &scope(&source, context.nested(children_start)),
+                    )?
+                };
+                source.children = Some(children);
+
+                let html = to_html::<T>(&source, tag)?;
+                end.after(&html, ContentType::Html);
+
+                Ok(())
             }));
         }
+
+        return Ok(());
     }
 
+    let html = to_html::<T>(&source, tag)?;
+
+    el.start_tag().remove();
+    el.before(&html, ContentType::Html);
+
     Ok(())
 }
 
-fn inject_attributes_from_original_element(
-    el: &lol_html::html_content::Element,
-    mut html: String,
-) -> String {
-    if let Some(content) = el.get_attribute("id") {
+fn to_html<T: HtmplateElement + ToHtml>(
+    source: &ElementAttributes,
+    tag: String,
+) -> Result<String, Box<dyn core::error::Error + Send + Sync + 'static>> {
+    let htmplate = T::from_attributes(source)?;
+
+    match htmplate.to_html() {
+        Ok(html) => Ok(inject_attributes_from_original_element(source, html)),
+        Err(kind) => Err(Box::new(HtmplateError {
+            tag,
+            location: source.location.clone(),
+            kind,
+        })),
+    }
+}
+
+fn inject_attributes_from_original_element(source: &ElementAttributes, mut html: String) -> String {
+    if let Some(content) = source.attribute("id") {
         create_or_replace_html_attribute("id", &content, &mut html);
     }
-    if let Some(content) = el.get_attribute("aria-label") {
+    if let Some(content) = source.attribute("aria-label") {
         create_or_replace_html_attribute("aria-label", &content, &mut html);
     }
+    if let Some(content) = source.attribute("slot") {
+        create_or_replace_html_attribute("slot", &content, &mut html);
+    }
 
-    if let Some(content) = el.get_attribute("style") {
+    if let Some(content) = source.attribute("style") {
         create_or_prepend_html_attribute("style", &content, ";", &mut html);
     }
-    if let Some(content) = el.get_attribute("class") {
+    if let Some(content) = source.attribute("class") {
         create_or_prepend_html_attribute("class", &content, " ", &mut html);
     }
 
@@ -160,6 +278,29 @@ impl core::error::Error for HtmplateError {
 pub enum HtmplateErrorKind {
     #[non_exhaustive]
     InvalidAttribute { attribute: String, expected: String },
+
+    #[non_exhaustive]
+    InvalidChildren {
+        source: lol_html::errors::RewritingError,
+    },
+
+    #[non_exhaustive]
+    ReadFile {
+        path: PathBuf,
+        source: Box<dyn core::error::Error + Send + Sync + 'static>,
+    },
+
+    #[non_exhaustive]
+    UnexpectedChildren { expected: String },
+
+    #[non_exhaustive]
+    IncludeCycle { chain: Vec<PathBuf> },
+
+    #[non_exhaustive]
+    Partial {
+        path: PathBuf,
+        source: Box<dyn core::error::Error + Send + Sync + 'static>,
+    },
 }
 impl core::fmt::Display for HtmplateErrorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -169,12 +310,33 @@ impl core::fmt::Display for HtmplateErrorKind {
                 expected,
                 ..
             } => write!(f, "invalid attribute `{attribute}`, {expected}"),
+            Self::InvalidChildren { .. } => write!(f, "could not read children"),
+            Self::UnexpectedChildren { expected, .. } => {
+                write!(f, "unexpected children, {expected}")
+            }
+            Self::ReadFile { path, .. } => {
+                write!(f, "could not read `{}`", path.opinionated_display())
+            }
+            Self::IncludeCycle { chain, .. } => {
+                let chain: Vec<String> = chain
+                    .iter()
+                    .map(|path| format!("`{}`", path.opinionated_display()))
+                    .collect();
+                write!(f, "include cycle {}", chain.join(" -> "))
+            }
+            Self::Partial { path, .. } => {
+                write!(f, "could not template `{}`", path.opinionated_display())
+            }
         }
     }
 }
 impl core::error::Error for HtmplateErrorKind {
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
-        None
+        match &self {
+            Self::InvalidChildren { source, .. } => Some(source),
+            Self::ReadFile { source, .. } | Self::Partial { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
     }
 }
 impl HtmplateErrorKind {
@@ -185,4 +347,27 @@ impl HtmplateErrorKind {
             expected: expected.to_string(),
         }
     }
+
+    #[allow(missing_docs)]
+    pub fn invalid_children(source: lol_html::errors::RewritingError) -> Self {
+        Self::InvalidChildren { source }
+    }
+
+    #[allow(missing_docs)]
+    pub fn unexpected_children<S: ToString>(expected: S) -> Self {
+        Self::UnexpectedChildren {
+            expected: expected.to_string(),
+        }
+    }
+
+    #[allow(missing_docs)]
+    pub fn read_file<E: Into<Box<dyn core::error::Error + Send + Sync + 'static>>>(
+        path: &Path,
+        source: E,
+    ) -> Self {
+        Self::ReadFile {
+            path: path.to_path_buf(),
+            source: source.into(),
+        }
+    }
 }
@@ -0,0 +1,179 @@
+use std::{fs, path::Path};
+
+use serde_json::Value;
+
+use crate::htmplates::HtmplateErrorKind;
+
+/// The rows of a table read from a file.
+pub(super) struct Data {
+    /// The keys of the values, in the order they were first seen.
+    pub keys: Vec<String>,
+    /// The rows, each a list of key value pairs.
+    pub rows: Vec<Vec<(String, String)>>,
+}
+impl Data {
+    /// Read the data from a JSON or CSV file.
+    ///
+    /// JSON files must contain an array of objects, CSV files must start with a header row.
+    pub fn read(path: &Path) -> Result<Self, HtmplateErrorKind> {
+        let contents = fs::read_to_string(path)
+            .map_err(|source| HtmplateErrorKind::read_file(path, source))?;
+
+        let is_csv = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+
+        if is_csv {
+            Ok(Self::from_csv(&contents))
+        } else {
+            Self::from_json(&contents).map_err(|source| HtmplateErrorKind::read_file(path, source))
+        }
+    }
+
+    fn from_json(contents: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(contents).map_err(|error| error.to_string())?;
+        let Value::Array(items) = value else {
+            return Err("expected an array of objects".to_string());
+        };
+
+        let mut keys: Vec<String> = Vec::new();
+        let mut rows = Vec::new();
+        for item in items {
+            let Value::Object(object) = item else {
+                return Err("expected an array of objects".to_string());
+            };
+
+            let row: Vec<(String, String)> = object
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::Null => String::new(),
+                        Value::String(value) => value,
+                        value => value.to_string(),
+                    };
+                    (key, value)
+                })
+                .collect();
+
+            for (key, _) in &row {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+            rows.push(row);
+        }
+
+        Ok(Self { keys, rows })
+    }
+
+    fn from_csv(contents: &str) -> Self {
+        let mut records = parse_csv(contents).into_iter();
+        let keys = records.next().unwrap_or_default();
+
+        let rows = records
+            .map(|record| keys.iter().cloned().zip(record).collect())
+            .collect();
+
+        Self { keys, rows }
+    }
+}
+
+/// Parse CSV into records, fields may be quoted with `"` and quotes are escaped as `""`.
+fn parse_csv(contents: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+
+    let mut chars = contents.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => record.push(core::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(core::mem::take(&mut field));
+                records.push(core::mem::take(&mut record));
+            }
+            char => field.push(char),
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records.retain(|record| !(record.len() == 1 && record[0].is_empty()));
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Data, parse_csv};
+
+    #[test]
+    fn quoted_commas_and_escaped_quotes() {
+        let records = parse_csv("name,quote\r\n\"Doe, Jane\",\"she said \"\"hi\"\"\"\r\n");
+
+        assert_eq!(
+            records,
+            vec![vec!["name", "quote"], vec!["Doe, Jane", r#"she said "hi""#],]
+        );
+    }
+
+    #[test]
+    fn quoted_newlines_and_blank_lines() {
+        let records = parse_csv("a,b\n\n\"line\nbreak\",2");
+
+        assert_eq!(records, vec![vec!["a", "b"], vec!["line\nbreak", "2"]]);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let data = Data::from_csv("a,b,c\n1,2\n1,2,3,4\n");
+
+        assert_eq!(data.keys, ["a", "b", "c"]);
+        assert_eq!(
+            data.rows,
+            vec![
+                vec![("a".into(), "1".into()), ("b".into(), "2".into())],
+                vec![
+                    ("a".into(), "1".into()),
+                    ("b".into(), "2".into()),
+                    ("c".into(), "3".into()),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn json_values_and_keys() {
+        let data =
+            Data::from_json(r#"[{"name": "a", "age": 3}, {"name": null, "joined": "2024-01-02"}]"#)
+                .unwrap();
+
+        assert_eq!(data.keys, ["name", "age", "joined"]);
+        assert_eq!(
+            data.rows,
+            vec![
+                vec![("name".into(), "a".into()), ("age".into(), "3".into())],
+                vec![
+                    ("name".into(), String::new()),
+                    ("joined".into(), "2024-01-02".into()),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn json_must_be_an_array_of_objects() {
+        assert!(Data::from_json(r#"{"name": "a"}"#).is_err());
+        assert!(Data::from_json("[1, 2]").is_err());
+        assert!(Data::from_json("[").is_err());
+    }
+}
@@ -0,0 +1,304 @@
+mod data;
+
+use core::{cell::Cell, str::FromStr};
+use std::path::PathBuf;
+
+use htmplate_derive::HtmplateElement;
+use lol_html::{Settings, element, rewrite_str};
+
+use crate::htmplates::{HtmplateErrorKind, ToHtml};
+
+use crate as htmplate;
+
+/// The type of the values in a column, used when sorting.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(missing_docs)]
+pub enum ColumnType {
+    #[default]
+    Text,
+    Number,
+    Date,
+}
+impl ColumnType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Number => "number",
+            Self::Date => "date",
+        }
+    }
+}
+
+/// The alignment of the values in a column.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(missing_docs)]
+pub enum ColumnAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+impl ColumnAlign {
+    fn class(&self) -> &'static str {
+        match self {
+            Self::Start => "align-start",
+            Self::Center => "align-center",
+            Self::End => "align-end",
+        }
+    }
+}
+
+/// A column in a table, `key=header` optionally followed by `:type` and `:align`.
+///
+/// The type is one of [text, number, date], numbers are aligned to the end by default. The
+/// alignment is one of [start, center, end].
+#[derive(Debug, Clone)]
+pub struct Column {
+    /// The key of the column's values in the data.
+    pub key: String,
+    /// The text of the column's header.
+    pub header: String,
+    /// The type of the column's values.
+    pub kind: ColumnType,
+    /// The alignment of the column's values.
+    pub align: ColumnAlign,
+}
+impl Column {
+    fn from_key(key: String) -> Self {
+        Self {
+            header: key.clone(),
+            key,
+            kind: ColumnType::default(),
+            align: ColumnAlign::default(),
+        }
+    }
+}
+impl FromStr for Column {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // without a header the key is also the header
+        let (key, rest) = match s.split_once('=') {
+            Some((key, rest)) => (key, rest),
+            None => (s.split(':').next().unwrap_or_default(), s),
+        };
+        let mut parts = rest.split(':').map(str::trim);
+
+        let key = key.trim();
+        let header = parts.next().unwrap_or_default();
+        if key.is_empty() || header.is_empty() {
+            return Err(());
+        }
+
+        let kind = match parts.next() {
+            None | Some("text") => ColumnType::Text,
+            Some("number") => ColumnType::Number,
+            Some("date") => ColumnType::Date,
+            Some(_) => return Err(()),
+        };
+
+        let align = match parts.next() {
+            None if matches!(kind, ColumnType::Number) => ColumnAlign::End,
+            None => ColumnAlign::Start,
+            Some("start") => ColumnAlign::Start,
+            Some("center") => ColumnAlign::Center,
+            Some("end") => ColumnAlign::End,
+            Some(_) => return Err(()),
+        };
+
+        if parts.next().is_some() {
+            return Err(());
+        }
+
+        Ok(Self {
+            key: key.to_string(),
+            header: header.to_string(),
+            kind,
+            align,
+        })
+    }
+}
+
+#[derive(HtmplateElement)]
+/// a table with sortable columns, made from child rows or a JSON or CSV file
+pub struct Table {
+    /// this should be a comma separated list of columns, each `key=header` optionally followed by `:type` and `:align`
+    #[htmplate(separator = ",")]
+    pub columns: Vec<Column>,
+    /// this should be the path to a JSON or CSV file containing the rows, relative to this document
+    #[htmplate(path)]
+    pub src: Option<PathBuf>,
+    /// this should be the table's caption
+    pub caption: Option<String>,
+    /// the `<tr>` elements of the table, these come after any rows from the file
+    #[htmplate(children)]
+    pub children: Option<String>,
+}
+
+impl ToHtml for Table {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self {
+            mut columns,
+            src,
+            caption,
+            children,
+        } = self;
+
+        let mut rows = String::new();
+
+        if let Some(src) = src {
+            let data = data::Data::read(&src)?;
+
+            if columns.is_empty() {
+                columns = data.keys.into_iter().map(Column::from_key).collect();
+            }
+
+            for row in data.rows {
+                rows.push_str("<tr>");
+                for column in &columns {
+                    let value = row
+                        .iter()
+                        .find(|(key, _)| key == &column.key)
+                        .map(|(_, value)| value.as_str())
+                        .unwrap_or_default();
+                    rows.push_str(&format!(
+                        r#"<td class="{}">{}</td>"#,
+                        column.align.class(),
+                        escape_html(value)
+                    ));
+                }
+                rows.push_str("</tr>\n");
+            }
+        }
+
+        if let Some(children) = children {
+            rows.push_str(&align_children(&children, &columns)?);
+        }
+
+        if columns.is_empty() {
+            return Err(HtmplateErrorKind::invalid_attribute(
+                "columns",
+                "there must be at least one column",
+            ));
+        }
+
+        let headers: String = columns
+            .iter()
+            .map(|column| {
+                format!(
+                    r#"<th scope="col" class="{}" data-type="{}">{}</th>"#,
+                    column.align.class(),
+                    column.kind.as_str(),
+                    column.header
+                )
+            })
+            .collect();
+
+        let caption = caption
+            .map(|caption| format!("<caption>{caption}</caption>"))
+            .unwrap_or_default();
+
+        Ok(format!(
+            include_str!("template.html"),
+            caption = caption,
+            headers = headers,
+            rows = rows,
+        ))
+    }
+}
+
+/// Add the alignment of each column to the cells of the child rows.
+fn align_children(children: &str, columns: &[Column]) -> Result<String, HtmplateErrorKind> {
+    let index = Cell::new(0);
+
+    rewrite_str(
+        children,
+        Settings {
+            element_content_handlers: vec![
+                element!("tr", |_| {
+                    index.set(0);
+                    Ok(())
+                }),
+                element!("td, th", |el| {
+                    if let Some(column) = columns.get(index.get()) {
+                        let class = match el.get_attribute("class") {
+                            Some(class) => format!("{} {class}", column.align.class()),
+                            None => column.align.class().to_string(),
+                        };
+                        el.set_attribute("class", &class)?;
+                    }
+                    index.set(index.get() + 1);
+                    Ok(())
+                }),
+            ],
+            ..Settings::new()
+        },
+    )
+    .map_err(HtmplateErrorKind::invalid_children)
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::{Column, ColumnAlign, ColumnType, align_children};
+
+    #[test]
+    fn column_with_header() {
+        let column = Column::from_str(" joined = Date joined : date : center ").unwrap();
+
+        assert_eq!(column.key, "joined");
+        assert_eq!(column.header, "Date joined");
+        assert!(matches!(column.kind, ColumnType::Date));
+        assert!(matches!(column.align, ColumnAlign::Center));
+    }
+
+    #[test]
+    fn column_without_header() {
+        let column = Column::from_str("age:number").unwrap();
+
+        assert_eq!(column.key, "age");
+        assert_eq!(column.header, "age");
+        assert!(matches!(column.kind, ColumnType::Number));
+        assert!(matches!(column.align, ColumnAlign::End));
+
+        let column = Column::from_str("name").unwrap();
+
+        assert_eq!(column.key, "name");
+        assert_eq!(column.header, "name");
+        assert!(matches!(column.kind, ColumnType::Text));
+        assert!(matches!(column.align, ColumnAlign::Start));
+    }
+
+    #[test]
+    fn invalid_columns() {
+        assert!(Column::from_str("").is_err());
+        assert!(Column::from_str("=Name").is_err());
+        assert!(Column::from_str("age:integer").is_err());
+        assert!(Column::from_str("age:number:right").is_err());
+        assert!(Column::from_str("age:number:end:extra").is_err());
+    }
+
+    #[test]
+    fn children_are_aligned() {
+        let columns = ["name", "age:number"].map(|column| Column::from_str(column).unwrap());
+        let children = align_children(
+            r#"<tr><th scope="row">a</th><td class="x">1</td></tr><tr><td>b</td><td>2</td><td>3</td></tr>"#,
+            &columns,
+        )
+        .unwrap();
+
+        assert_eq!(
+            children,
+            r#"<tr><th scope="row" class="align-start">a</th><td class="align-end x">1</td></tr><tr><td class="align-start">b</td><td class="align-end">2</td><td>3</td></tr>"#
+        );
+    }
+}
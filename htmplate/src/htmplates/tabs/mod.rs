@@ -0,0 +1,94 @@
+use core::cell::RefCell;
+
+use htmplate_derive::HtmplateElement;
+use lol_html::{Settings, element, rewrite_str};
+
+use crate::htmplates::{HtmplateErrorKind, ToHtml};
+
+use crate as htmplate;
+
+#[derive(HtmplateElement)]
+/// a set of tabs, each tab is a `htmplate:tab` child, all tabs are shown if scripts are disabled
+pub struct Tabs {
+    /// this should be the ID of the tabs
+    pub id: String,
+    /// the `htmplate:tab` elements
+    #[htmplate(children)]
+    pub children: Option<String>,
+}
+
+#[derive(HtmplateElement)]
+/// a tab inside of `htmplate:tabs`
+pub struct Tab {
+    /// this should be the tab's label
+    pub label: String,
+    /// the contents of the tab
+    #[htmplate(children)]
+    pub children: Option<String>,
+}
+
+impl ToHtml for Tab {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self { label, children } = self;
+
+        Ok(format!(
+            include_str!("panel.template.html"),
+            label = label,
+            children = children.unwrap_or_default(),
+        ))
+    }
+}
+
+impl ToHtml for Tabs {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self { id, children } = self;
+
+        // Number the panels, panels of nested tabs have already lost their label.
+        let labels = RefCell::new(Vec::new());
+        let panels = rewrite_str(
+            &children.unwrap_or_default(),
+            Settings {
+                element_content_handlers: vec![element!(
+                    r#"[role="tabpanel"][data-tab-label]"#,
+                    |el| {
+                        let mut labels = labels.borrow_mut();
+                        let index = labels.len();
+
+                        labels.push(el.get_attribute("data-tab-label").unwrap_or_default());
+                        el.remove_attribute("data-tab-label");
+                        el.set_attribute("id", &format!("{id}/panel/{index}"))?;
+                        el.set_attribute("aria-labelledby", &format!("{id}/tab/{index}"))?;
+
+                        Ok(())
+                    }
+                )],
+                ..Settings::new()
+            },
+        )
+        .map_err(HtmplateErrorKind::invalid_children)?;
+
+        let tabs: String = labels
+            .into_inner()
+            .into_iter()
+            .enumerate()
+            .map(|(index, label)| {
+                let selected = index == 0;
+                format!(
+                    include_str!("tab.template.html"),
+                    id = id,
+                    index = index,
+                    label = label,
+                    selected = selected,
+                    tabindex = if selected { "0" } else { "-1" },
+                )
+            })
+            .collect();
+
+        Ok(format!(
+            include_str!("template.html"),
+            id = id,
+            tabs = tabs,
+            panels = panels,
+        ))
+    }
+}
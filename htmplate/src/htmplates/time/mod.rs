@@ -0,0 +1,70 @@
+use core::str::FromStr;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use htmplate_derive::HtmplateElement;
+
+use crate::htmplates::{HtmplateErrorKind, ToHtml};
+
+use crate as htmplate;
+
+/// How a timestamp is shown.
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs)]
+pub enum TimeFormat {
+    Relative,
+    Date,
+    #[default]
+    DateTime,
+}
+impl TimeFormat {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Relative => "relative",
+            Self::Date => "date",
+            Self::DateTime => "datetime",
+        }
+    }
+}
+impl FromStr for TimeFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "relative" => Ok(Self::Relative),
+            "date" => Ok(Self::Date),
+            "datetime" => Ok(Self::DateTime),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(HtmplateElement)]
+/// a timestamp that `time.ts` shows in the viewer's time zone
+pub struct Time {
+    /// this should be an RFC 3339 timestamp, e.g. `2025-01-31T09:30:00Z`
+    pub utc: DateTime<Utc>,
+    /// this should be one of [relative, date, datetime], defaults to datetime
+    pub format: Option<TimeFormat>,
+}
+
+impl ToHtml for Time {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self { utc, format } = self;
+        let format = format.unwrap_or_default();
+
+        // Shown until the script replaces it, or if scripts are disabled.
+        let fallback = match format {
+            TimeFormat::Date => utc.format("%-d %b %Y").to_string(),
+            TimeFormat::Relative | TimeFormat::DateTime => {
+                utc.format("%-d %b %Y, %H:%M UTC").to_string()
+            }
+        };
+
+        Ok(format!(
+            include_str!("template.html"),
+            datetime = utc.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            format = format.as_str(),
+            fallback = fallback,
+        ))
+    }
+}
@@ -18,16 +18,26 @@ pub struct Title {
 
 impl ToHtml for Title {
     fn to_html(self) -> Result<String, HtmplateErrorKind> {
-        let mut icon = self
-            .icon
-            .map(|icon| icon.svg())
-            .unwrap_or_default()
-            .to_string();
-        create_or_prepend_html_attribute("class", "mauve", " ", &mut icon);
         let text = self.text.unwrap_or_default();
 
-        let content = format!(include_str!("template.html"), icon = icon, text = text);
+        let content = format!(
+            include_str!("template.html"),
+            content = icon_and_text(self.icon, Some(&text), "h1")
+        );
 
         Ok(content)
     }
 }
+
+/// The markup of a title, its icon coloured mauve followed by its text in a `tag` element.
+pub(crate) fn icon_and_text(icon: Option<Icon>, text: Option<&str>, tag: &str) -> String {
+    let mut icon = icon.map(|icon| icon.svg()).unwrap_or_default().to_string();
+    if !icon.is_empty() {
+        create_or_prepend_html_attribute("class", "mauve", " ", &mut icon);
+    }
+    let text = text
+        .map(|text| format!("<{tag}>{text}</{tag}>"))
+        .unwrap_or_default();
+
+    format!("{icon}{text}")
+}
@@ -0,0 +1,58 @@
+use htmplate_derive::HtmplateElement;
+
+use crate::htmplates::{Alert, AlertStyle, HtmplateErrorKind, ToHtml};
+
+use crate as htmplate;
+
+#[derive(HtmplateElement)]
+/// the container that `showToast` from `toast.ts` shows toasts in
+pub struct Toasts {
+    /// this should be the ID of the container, defaults to "toasts"
+    pub id: Option<String>,
+}
+
+impl ToHtml for Toasts {
+    fn to_html(self) -> Result<String, HtmplateErrorKind> {
+        let Self { id } = self;
+
+        // The script clones the same markup as the alert htmplate.
+        let templates = [
+            ("error", AlertStyle::Error),
+            ("warning", AlertStyle::Warning),
+            ("success", AlertStyle::Success),
+            ("info", AlertStyle::Info),
+            ("basic", AlertStyle::Basic),
+        ]
+        .into_iter()
+        .map(|(name, status)| {
+            let alert = Alert { status, text: None }.to_html()?;
+            Ok(format!(
+                r#"<template data-status="{name}">{alert}</template>"#
+            ))
+        })
+        .collect::<Result<Vec<_>, HtmplateErrorKind>>()?;
+
+        Ok(format!(
+            include_str!("template.html"),
+            id = id.as_deref().unwrap_or("toasts"),
+            templates = templates.join("\n"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_files::{replace_index, write_files};
+
+    #[test]
+    fn a_template_for_each_status() {
+        let directory = write_files("toasts", &[("index.html", "<htmplate:toasts/>")]);
+        let html = replace_index(&directory).unwrap();
+
+        assert!(html.contains(r#"<div class="toasts" id="toasts" role="status""#));
+        for status in ["error", "warning", "success", "info", "basic"] {
+            assert!(html.contains(&format!(r#"<template data-status="{status}">"#)));
+        }
+        assert!(html.contains(r#"<aside class="alert error">"#));
+    }
+}
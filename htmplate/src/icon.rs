@@ -1,5 +1,10 @@
+//! The filled ionicons from https://ionic.io/ionicons
+
 use core::str::FromStr;
 
+/// A filled ionicon.
+#[derive(Debug, Clone, Copy)]
+#[allow(missing_docs)]
 pub enum Icon {
     Accessibility,
     AddCircle,
@@ -1042,6 +1047,7 @@ impl FromStr for Icon {
 }
 
 impl Icon {
+    /// The icon's SVG.
     pub fn svg(&self) -> &'static str {
         match self {
             Self::Accessibility => include_str!("icons/accessibility.svg"),
@@ -2,21 +2,35 @@
 //! Library to make reusable components in HTML via `<htmplate:... />` elements.
 
 pub mod assets;
+mod attribute_source;
+mod context;
 mod htmplate_element;
 pub mod htmplates;
-mod icon;
+pub mod icon;
+mod slot;
+#[cfg(test)]
+mod test_files;
 
 use std::{path::Path, sync::LazyLock};
 
 use lol_html::{Settings, element, errors::RewritingError, rewrite_str};
 
-pub use htmplate_element::{Attribute, FromElementError, HtmplateElement, Location};
+pub use attribute_source::{AttributeSource, LocatedAttributes};
+pub use htmplate_element::{
+    Attribute, FromElementError, HtmplateElement, InvalidItem, Location, Unset,
+};
 pub use lol_html;
 use regex::Regex;
 
-use crate::htmplates::{
-    Alert, Footer, FormAlert, FormCheckInput, FormSubmit, FormTextInput, Hr, HtmplateError, Icon,
-    IconButton, Metadata, Title, replacer,
+use crate::{
+    context::Context,
+    htmplates::{
+        Alert, Block, Breadcrumbs, Card, Code, Dialog, Footer, Form, FormAlert, FormCheckInput,
+        FormRadioGroup, FormSelect, FormSubmit, FormTextInput, FormTextarea, Header, Hr,
+        HtmplateError, Icon, IconButton, Img, Include, Layout, Markdown, Metadata, Progress,
+        Skeleton, Spinner, Tab, Table, Tabs, Time, Title, Toasts, block_replacer, include_replacer,
+        layout_replacer, replacer, scoped_replacer,
+    },
 };
 
 /// The details for an htmplate
@@ -43,14 +57,36 @@ pub fn all_htmplate_details() -> Vec<HtmplateDetails> {
     vec![
         HtmplateDetails::new::<Title>(),
         HtmplateDetails::new::<Metadata>(),
+        HtmplateDetails::new::<Include>(),
+        HtmplateDetails::new::<Layout>(),
+        HtmplateDetails::new::<Block>(),
+        HtmplateDetails::new::<Header>(),
+        HtmplateDetails::new::<Breadcrumbs>(),
         HtmplateDetails::new::<Footer>(),
         HtmplateDetails::new::<Alert>(),
+        HtmplateDetails::new::<Toasts>(),
+        HtmplateDetails::new::<Spinner>(),
+        HtmplateDetails::new::<Progress>(),
+        HtmplateDetails::new::<Skeleton>(),
+        HtmplateDetails::new::<Form>(),
         HtmplateDetails::new::<FormAlert>(),
         HtmplateDetails::new::<FormTextInput>(),
+        HtmplateDetails::new::<FormTextarea>(),
         HtmplateDetails::new::<FormCheckInput>(),
+        HtmplateDetails::new::<FormSelect>(),
+        HtmplateDetails::new::<FormRadioGroup>(),
         HtmplateDetails::new::<FormSubmit>(),
         HtmplateDetails::new::<Icon>(),
         HtmplateDetails::new::<Hr>(),
+        HtmplateDetails::new::<Img>(),
+        HtmplateDetails::new::<Time>(),
+        HtmplateDetails::new::<Card>(),
+        HtmplateDetails::new::<Code>(),
+        HtmplateDetails::new::<Markdown>(),
+        HtmplateDetails::new::<Dialog>(),
+        HtmplateDetails::new::<Table>(),
+        HtmplateDetails::new::<Tabs>(),
+        HtmplateDetails::new::<Tab>(),
         HtmplateDetails::new::<IconButton>(),
     ]
 }
@@ -60,6 +96,21 @@ static GAP_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r">\s<").unwrap(
 
 /// Replace the htmplates in some source HTML.
 pub fn replace_htmplates(html: &str, html_path: &Path) -> Result<String, ReplaceHtmplateError> {
+    let html = rewrite_htmplates(html, &Context::new(html, html_path))?;
+
+    let html = collapse_whitespace(&html);
+
+    Ok(format!(
+        "<!-- htmplate v{} -->\n{html}",
+        env!("CARGO_PKG_VERSION")
+    ))
+}
+
+/// Replace the htmplates in a pass over some HTML.
+pub(crate) fn rewrite_htmplates(
+    html: &str,
+    context: &Context,
+) -> Result<String, ReplaceHtmplateError> {
     let tags: Vec<_> = all_htmplate_details()
         .into_iter()
         .map(|detail| detail.tag)
@@ -69,14 +120,10 @@ pub fn replace_htmplates(html: &str, html_path: &Path) -> Result<String, Replace
         let not_selectors: String = tags.iter().map(|tag| format!(":not({tag})")).collect();
 
         element!(format!("*{not_selectors}"), |el| {
-            if el.tag_name().starts_with("htmplate") {
+            if el.tag_name().starts_with("htmplate") && !context.is_suppressed() {
                 Err(Box::new(ReplaceHtmplateError::HtmplateDoesNotExist {
                     tag: el.tag_name(),
-                    location: Location::from_byte_index(
-                        el.source_location().bytes().start,
-                        html.as_bytes(),
-                        html_path,
-                    ),
+                    location: context.location(el.source_location().bytes().start),
                 }))
             } else {
                 Ok(())
@@ -89,30 +136,54 @@ pub fn replace_htmplates(html: &str, html_path: &Path) -> Result<String, Replace
         Settings {
             #[rustfmt::skip]
             element_content_handlers: vec![
-                element!(Metadata::tag(), |el| replacer::<Metadata>(el, html, html_path)),
-                element!(Title::tag(), |el| replacer::<Title>(el, html, html_path)),
-                element!(Icon::tag(), |el| replacer::<Icon>(el, html, html_path)),
-                element!(Footer::tag(), |el| replacer::<Footer>(el, html, html_path)),
-                element!(Alert::tag(), |el| replacer::<Alert>(el, html, html_path)),
-                element!(Hr::tag(), |el| replacer::<Hr>(el, html, html_path)),
-                element!(FormAlert::tag(), |el| replacer::<FormAlert>(el, html, html_path)),
-                element!(FormTextInput::tag(), |el| replacer::<FormTextInput>(el, html, html_path)),
-                element!(FormCheckInput::tag(), |el| replacer::<FormCheckInput>(el, html, html_path)),
-                element!(FormSubmit::tag(), |el| replacer::<FormSubmit>(el, html, html_path)),
-                element!(IconButton::tag(), |el| replacer::<IconButton>(el, html, html_path)),
+                element!(Metadata::tag(), |el| replacer::<Metadata>(el, context)),
+                element!(Title::tag(), |el| replacer::<Title>(el, context)),
+                element!(Layout::tag(), |el| layout_replacer(el, context)),
+                element!(Block::tag(), |el| block_replacer(el, context)),
+                element!(Include::tag(), |el| include_replacer(el, context)),
+                element!(Icon::tag(), |el| replacer::<Icon>(el, context)),
+                element!(Header::tag(), |el| replacer::<Header>(el, context)),
+                element!(Breadcrumbs::tag(), |el| replacer::<Breadcrumbs>(el, context)),
+                element!(Footer::tag(), |el| replacer::<Footer>(el, context)),
+                element!(Alert::tag(), |el| replacer::<Alert>(el, context)),
+                element!(Toasts::tag(), |el| replacer::<Toasts>(el, context)),
+                element!(Spinner::tag(), |el| replacer::<Spinner>(el, context)),
+                element!(Progress::tag(), |el| replacer::<Progress>(el, context)),
+                element!(Skeleton::tag(), |el| replacer::<Skeleton>(el, context)),
+                element!(Hr::tag(), |el| replacer::<Hr>(el, context)),
+                element!(Img::tag(), |el| replacer::<Img>(el, context)),
+                element!(Time::tag(), |el| replacer::<Time>(el, context)),
+                element!(Card::tag(), |el| replacer::<Card>(el, context)),
+                element!(Code::tag(), |el| replacer::<Code>(el, context)),
+                element!(Markdown::tag(), |el| replacer::<Markdown>(el, context)),
+                element!(Dialog::tag(), |el| replacer::<Dialog>(el, context)),
+                element!(Table::tag(), |el| replacer::<Table>(el, context)),
+                element!(Tabs::tag(), |el| replacer::<Tabs>(el, context)),
+                element!(Tab::tag(), |el| replacer::<Tab>(el, context)),
+                element!(Form::tag(), |el| scoped_replacer::<Form>(el, context, Form::scope)),
+                element!(FormAlert::tag(), |el| replacer::<FormAlert>(el, context)),
+                element!(FormTextInput::tag(), |el| replacer::<FormTextInput>(el, context)),
+                element!(FormTextarea::tag(), |el| replacer::<FormTextarea>(el, context)),
+                element!(FormCheckInput::tag(), |el| replacer::<FormCheckInput>(el, context)),
+                element!(FormSelect::tag(), |el| replacer::<FormSelect>(el, context)),
+                element!(FormRadioGroup::tag(), |el| replacer::<FormRadioGroup>(el, context)),
+                element!(FormSubmit::tag(), |el| replacer::<FormSubmit>(el, context)),
+                element!(IconButton::tag(), |el| replacer::<IconButton>(el, context)),
                 not_found_handler,
             ],
             ..Settings::new()
         },
     )?;
 
-    let html = NEWLINE_REGEX.replace_all(&html, " ");
+    Ok(html)
+}
+
+/// Collapse the whitespace in some templated HTML.
+pub(crate) fn collapse_whitespace(html: &str) -> String {
+    let html = NEWLINE_REGEX.replace_all(html, " ");
     let html = GAP_REGEX.replace_all(&html, ">\n<");
 
-    Ok(format!(
-        "<!-- htmplate v{} -->\n{html}",
-        env!("CARGO_PKG_VERSION")
-    ))
+    html.into_owned()
 }
 
 /// Error variants for replacing the htmplates.
@@ -164,6 +235,10 @@ impl From<RewritingError> for ReplaceHtmplateError {
                     Self::InvalidHtmplate {
                         source: *error.downcast::<FromElementError>().unwrap(),
                     }
+                } else if error.is::<HtmplateError>() {
+                    Self::HtmplateError {
+                        source: *error.downcast::<HtmplateError>().unwrap(),
+                    }
                 } else {
                     Self::RewriteError {
                         source: RewritingError::ContentHandlerError(error),
@@ -0,0 +1,59 @@
+use core::{cell::RefCell, ops::Range};
+use std::rc::Rc;
+
+use lol_html::{Settings, element, errors::RewritingError, rewrite_str};
+
+/// Take the elements with a `slot` attribute matching `name` out of some HTML.
+///
+/// Returns the HTML without the slot's elements, and the HTML of the slot's elements.
+pub(crate) fn take_slot(html: &str, name: &str) -> Result<(String, String), RewritingError> {
+    let ranges: Rc<RefCell<Vec<Range<usize>>>> = Rc::default();
+
+    rewrite_str(
+        html,
+        Settings {
+            element_content_handlers: vec![element!(format!(r#"[slot="{name}"]"#), |el| {
+                record_range(el, &ranges);
+                Ok(())
+            })],
+            ..Settings::new()
+        },
+    )?;
+
+    let mut ranges = ranges.take();
+    ranges.sort_by_key(|range| range.start);
+
+    let mut rest = String::new();
+    let mut slot = String::new();
+    let mut position = 0;
+    for range in ranges {
+        // Slots nested inside of another slot are taken with it.
+        if range.start < position {
+            continue;
+        }
+
+        rest.push_str(&html[position..range.start]);
+        slot.push_str(&html[range.clone()]);
+        position = range.end;
+    }
+    rest.push_str(&html[position..]);
+
+    Ok((rest, slot))
+}
+
+/// Record the range of an element once its end tag is reached.
+fn record_range(el: &mut lol_html::html_content::Element, ranges: &Rc<RefCell<Vec<Range<usize>>>>) {
+    let location = el.source_location().bytes();
+
+    if el.is_self_closing() || !el.can_have_content() {
+        ranges.borrow_mut().push(location);
+    } else if let Some(handlers) = el.end_tag_handlers() {
+        let ranges = Rc::clone(ranges);
+        handlers.push(Box::new(move |end| {
+            ranges
+                .borrow_mut()
+                .push(location.start..end.source_location().bytes().end);
+            Ok(())
+        }));
+    }
+}
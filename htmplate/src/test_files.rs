@@ -0,0 +1,40 @@
+//! Documents written to a temporary directory for the tests of htmplates that read files.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::replace_htmplates;
+
+/// Write some files into a directory of their own, returning the directory.
+pub fn write_files(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("htmplate-{name}-{}", std::process::id()));
+    for (path, contents) in files {
+        let path = directory.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+    directory
+}
+
+/// Replace the htmplates in the `index.html` of a directory.
+pub fn replace_index(directory: &Path) -> Result<String, crate::ReplaceHtmplateError> {
+    let path = directory.join("index.html");
+    let html = fs::read_to_string(&path).unwrap();
+    replace_htmplates(&html, &path)
+}
+
+/// Replace the htmplates in the `index.html` of a directory, returning the messages of the error
+/// and its sources.
+pub fn error_messages(directory: &Path) -> Vec<String> {
+    let error = replace_index(directory).unwrap_err();
+
+    let mut messages = Vec::new();
+    let mut error: Option<&dyn core::error::Error> = Some(&error);
+    while let Some(source) = error {
+        messages.push(source.to_string());
+        error = source.source();
+    }
+    messages
+}